@@ -1,8 +1,26 @@
 /// The runtime configuration of the execution engine
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone)]
 pub struct EngineConfig {
     // feature flags go here
     use_system_contracts: bool,
+    execution_journal: bool,
+    max_runtime_call_stack_height: Option<u32>,
+    strict_argument_checking: bool,
+    #[cfg(feature = "test-support")]
+    zero_system_contract_gas: bool,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        EngineConfig {
+            use_system_contracts: false,
+            execution_journal: false,
+            max_runtime_call_stack_height: None,
+            strict_argument_checking: true,
+            #[cfg(feature = "test-support")]
+            zero_system_contract_gas: false,
+        }
+    }
 }
 
 impl EngineConfig {
@@ -19,4 +37,70 @@ impl EngineConfig {
         self.use_system_contracts = use_system_contracts;
         self
     }
+
+    /// Returns `true` if an ordered journal of state operations, transfers and host function calls
+    /// should be recorded in the execution effect of each deploy.
+    pub fn execution_journal(self) -> bool {
+        self.execution_journal
+    }
+
+    pub fn with_execution_journal(mut self, execution_journal: bool) -> EngineConfig {
+        self.execution_journal = execution_journal;
+        self
+    }
+
+    /// Returns the maximum height of the runtime call stack, if limited.
+    ///
+    /// The height counts the session code plus every contract called on top of it, so a limit of
+    /// `n` allows up to `n - 1` nested contract calls.  Unlimited by default.
+    pub fn max_runtime_call_stack_height(self) -> Option<u32> {
+        self.max_runtime_call_stack_height
+    }
+
+    pub fn with_max_runtime_call_stack_height(
+        mut self,
+        max_runtime_call_stack_height: u32,
+    ) -> EngineConfig {
+        self.max_runtime_call_stack_height = Some(max_runtime_call_stack_height);
+        self
+    }
+
+    /// Returns `true` if the types of the arguments passed to a versioned contract call must match
+    /// the parameters declared by the called entry point.
+    pub fn strict_argument_checking(self) -> bool {
+        self.strict_argument_checking
+    }
+
+    /// Sets whether argument types of versioned contract calls are checked against the called
+    /// entry point.  Enabled by default.
+    ///
+    /// Disabling this lets a caller pass arguments of any type to an entry point, which then reads
+    /// them as its declared types.  A contract relying on the declared types for validation can be
+    /// handed malformed input, so this should only be disabled to reproduce the lenient behavior in
+    /// tests.
+    pub fn with_strict_argument_checking(mut self, strict_argument_checking: bool) -> EngineConfig {
+        self.strict_argument_checking = strict_argument_checking;
+        self
+    }
+
+    /// Returns `true` if gas consumed by calls into the mint, proof of stake and auction contracts
+    /// should not be charged.  Always `false` unless built with the `test-support` feature.
+    pub fn zero_system_contract_gas(self) -> bool {
+        #[cfg(feature = "test-support")]
+        {
+            self.zero_system_contract_gas
+        }
+        #[cfg(not(feature = "test-support"))]
+        {
+            false
+        }
+    }
+
+    /// Stops charging for gas consumed by calls into the mint, proof of stake and auction
+    /// contracts.  Intended only for tests which are not concerned with payment.
+    #[cfg(feature = "test-support")]
+    pub fn with_zero_system_contract_gas(mut self, zero_system_contract_gas: bool) -> EngineConfig {
+        self.zero_system_contract_gas = zero_system_contract_gas;
+        self
+    }
 }
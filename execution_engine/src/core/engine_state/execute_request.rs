@@ -3,7 +3,7 @@ use std::mem;
 use casper_types::ProtocolVersion;
 
 use super::{deploy_item::DeployItem, execution_result::ExecutionResult};
-use crate::shared::newtypes::Blake2bHash;
+use crate::shared::{gas::Gas, newtypes::Blake2bHash};
 
 #[derive(Debug)]
 pub struct ExecuteRequest {
@@ -11,6 +11,9 @@ pub struct ExecuteRequest {
     pub block_time: u64,
     pub deploys: Vec<Result<DeployItem, ExecutionResult>>,
     pub protocol_version: ProtocolVersion,
+    /// If set, caps the gas limit of the session code of every deploy, regardless of how much
+    /// was paid for it.
+    pub gas_limit: Option<Gas>,
 }
 
 impl ExecuteRequest {
@@ -25,6 +28,7 @@ impl ExecuteRequest {
             block_time,
             deploys,
             protocol_version,
+            gas_limit: None,
         }
     }
 
@@ -40,6 +44,7 @@ impl Default for ExecuteRequest {
             block_time: 0,
             deploys: vec![],
             protocol_version: Default::default(),
+            gas_limit: None,
         }
     }
 }
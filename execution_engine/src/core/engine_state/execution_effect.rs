@@ -1,16 +1,27 @@
 use casper_types::Key;
 
-use super::op::Op;
+use super::{execution_journal::ExecutionJournal, op::Op};
 use crate::shared::{additive_map::AdditiveMap, transform::Transform};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ExecutionEffect {
     pub ops: AdditiveMap<Key, Op>,
     pub transforms: AdditiveMap<Key, Transform>,
+    /// Ordered record of host function calls; empty unless the execution journal is enabled.
+    pub journal: ExecutionJournal,
 }
 
 impl ExecutionEffect {
     pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
-        ExecutionEffect { ops, transforms }
+        ExecutionEffect {
+            ops,
+            transforms,
+            journal: ExecutionJournal::default(),
+        }
+    }
+
+    pub fn with_journal(mut self, journal: ExecutionJournal) -> Self {
+        self.journal = journal;
+        self
     }
 }
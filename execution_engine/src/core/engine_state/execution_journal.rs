@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use casper_types::{Key, URef, U512};
+
+/// A single host function call recorded in an [`ExecutionJournal`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostFunctionCall {
+    host_function: String,
+    properties: BTreeMap<String, String>,
+    gas: U512,
+}
+
+impl HostFunctionCall {
+    pub fn new(host_function: String, properties: BTreeMap<String, String>, gas: U512) -> Self {
+        HostFunctionCall {
+            host_function,
+            properties,
+            gas,
+        }
+    }
+
+    /// The name of the host function, as used in the host function metrics.
+    pub fn host_function(&self) -> &str {
+        &self.host_function
+    }
+
+    /// The properties recorded by the host function, e.g. the sizes of its arguments.
+    pub fn properties(&self) -> &BTreeMap<String, String> {
+        &self.properties
+    }
+
+    /// The gas charged while the host function ran.  For a call into another contract this
+    /// includes all gas consumed by the callee, including by its own nested calls.
+    pub fn gas(&self) -> U512 {
+        self.gas
+    }
+}
+
+/// A single operation recorded in an [`ExecutionJournal`].
+///
+/// Keys and purses are held in their formatted string form, as returned by
+/// [`Key::to_formatted_string`] and [`URef::to_formatted_string`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JournalEntry {
+    /// A call to a host function, recorded as the host function returns.
+    HostFunction(HostFunctionCall),
+    /// A value was read from global state.
+    Read { key: String },
+    /// A value was written to global state.
+    Write { key: String },
+    /// A value was added to a value in global state.
+    Add { key: String },
+    /// Motes were transferred between two purses by the mint.
+    Transfer {
+        source: String,
+        target: String,
+        amount: U512,
+    },
+}
+
+impl JournalEntry {
+    pub fn read(key: &Key) -> Self {
+        JournalEntry::Read {
+            key: key.to_formatted_string(),
+        }
+    }
+
+    pub fn write(key: &Key) -> Self {
+        JournalEntry::Write {
+            key: key.to_formatted_string(),
+        }
+    }
+
+    pub fn add(key: &Key) -> Self {
+        JournalEntry::Add {
+            key: key.to_formatted_string(),
+        }
+    }
+
+    pub fn transfer(source: URef, target: URef, amount: U512) -> Self {
+        JournalEntry::Transfer {
+            source: source.to_formatted_string(),
+            target: target.to_formatted_string(),
+            amount,
+        }
+    }
+}
+
+/// An ordered record of the operations performed while executing a deploy: global state reads,
+/// writes and adds, transfers made by the mint, and host function calls.
+///
+/// Only populated when enabled via
+/// [`EngineConfig::with_execution_journal`](super::engine_config::EngineConfig::with_execution_journal).
+/// Host function calls are recorded as each host function returns, so a host function call appears
+/// after the state operations and transfers it caused, and a call into another contract appears
+/// after everything done by the callee.
+///
+/// Transfers are only recorded when the mint runs on the host, i.e. when
+/// [`EngineConfig::use_system_contracts`](super::engine_config::EngineConfig::use_system_contracts)
+/// is `false`.  The state operations of a transfer are recorded either way.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionJournal(Vec<JournalEntry>);
+
+impl ExecutionJournal {
+    pub fn new() -> Self {
+        ExecutionJournal::default()
+    }
+
+    pub fn push(&mut self, entry: JournalEntry) {
+        self.0.push(entry)
+    }
+
+    /// Appends all entries of `other` to this journal.
+    pub fn append(&mut self, other: &ExecutionJournal) {
+        self.0.extend(other.0.iter().cloned())
+    }
+
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.0
+    }
+
+    /// Returns the host function calls in this journal, in order, skipping all other entries.
+    pub fn host_function_calls(&self) -> impl Iterator<Item = &HostFunctionCall> {
+        self.0.iter().filter_map(|entry| match entry {
+            JournalEntry::HostFunction(host_function_call) => Some(host_function_call),
+            _ => None,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
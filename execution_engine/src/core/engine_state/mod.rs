@@ -5,6 +5,7 @@ mod error;
 pub mod executable_deploy_item;
 pub mod execute_request;
 pub mod execution_effect;
+pub mod execution_journal;
 pub mod execution_result;
 pub mod genesis;
 pub mod op;
@@ -16,6 +17,7 @@ pub mod upgrade;
 
 use std::{
     cell::RefCell,
+    cmp,
     collections::{BTreeMap, BTreeSet},
     rc::Rc,
 };
@@ -68,7 +70,7 @@ use crate::{
         wasm_prep::{self, Preprocessor},
     },
     storage::{
-        global_state::{CommitResult, StateProvider},
+        global_state::{self, CommitResult, StateKeysReader, StateProvider, StoredValueDiff},
         protocol_data::ProtocolData,
     },
 };
@@ -84,6 +86,8 @@ pub const SYSTEM_ACCOUNT_ADDR: AccountHash = AccountHash::new([0u8; 32]);
 
 const GENESIS_INITIAL_BLOCKTIME: u64 = 0;
 const ARG_AMOUNT: &str = "amount";
+const ARG_SOURCE: &str = "source";
+const ARG_TARGET: &str = "target";
 
 #[derive(Debug)]
 pub struct EngineState<S> {
@@ -146,6 +150,14 @@ where
         }
     }
 
+    /// Drops every system contract module cached by this engine, so the next execution parses
+    /// them from global state again.
+    ///
+    /// This only affects timing, never the results of execution.
+    pub fn clear_system_contract_cache(&self) {
+        self.system_contract_cache.clear()
+    }
+
     pub fn get_protocol_data(
         &self,
         protocol_version: ProtocolVersion,
@@ -733,11 +745,30 @@ where
         hash: Blake2bHash,
     ) -> Result<Option<TrackingCopy<S::Reader>>, Error> {
         match self.state.checkout(hash).map_err(Into::into)? {
-            Some(tc) => Ok(Some(TrackingCopy::new(tc))),
+            Some(tc) => Ok(Some(
+                TrackingCopy::new(tc).with_execution_journal(self.config.execution_journal()),
+            )),
             None => Ok(None),
         }
     }
 
+    /// Returns the per-key differences between the post states `before` and `after`, or `None`
+    /// if either of them is not found.
+    ///
+    /// Intended for tests only; see [`global_state::diff`].
+    pub fn diff_state(
+        &self,
+        correlation_id: CorrelationId,
+        before: Blake2bHash,
+        after: Blake2bHash,
+    ) -> Result<Option<BTreeMap<Key, StoredValueDiff>>, Error>
+    where
+        S::Reader: StateKeysReader<Key, StoredValue>,
+    {
+        global_state::diff(&self.state, correlation_id, before, after)
+            .map_err(|error| Error::Exec(error.into()))
+    }
+
     pub fn run_query(
         &self,
         correlation_id: CorrelationId,
@@ -793,6 +824,7 @@ where
                         exec_request.parent_state_hash,
                         BlockTime::new(exec_request.block_time),
                         deploy_item,
+                        exec_request.gas_limit,
                     ),
                 },
             };
@@ -1175,6 +1207,112 @@ where
         Ok(execution_result)
     }
 
+    /// Transfers `amount` from `source` to `target` by calling the mint's `transfer` entry point
+    /// directly as the system account, without executing any session code.
+    ///
+    /// Returns the mint's own result, if it produced one, along with the execution result holding
+    /// the effects to be committed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mint_transfer(
+        &self,
+        correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+        source: URef,
+        target: URef,
+        amount: U512,
+    ) -> Result<(Option<Result<(), mint::Error>>, ExecutionResult), RootNotFound> {
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                let error = Error::InvalidProtocolVersion(protocol_version);
+                return Ok((None, ExecutionResult::precondition_failure(error)));
+            }
+            Err(error) => {
+                let error = Error::Exec(error.into());
+                return Ok((None, ExecutionResult::precondition_failure(error)));
+            }
+        };
+
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+
+        let tracking_copy = match self.tracking_copy(prestate_hash) {
+            Err(error) => return Ok((None, ExecutionResult::precondition_failure(error))),
+            Ok(None) => return Err(RootNotFound::new(prestate_hash)),
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        };
+
+        let mint_contract = match tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, protocol_data.mint())
+        {
+            Ok(contract) => contract,
+            Err(error) => {
+                return Ok((None, ExecutionResult::precondition_failure(error.into())));
+            }
+        };
+
+        let mint_module = {
+            let contract_wasm_hash = mint_contract.contract_wasm_hash();
+            let use_system_contracts = self.config.use_system_contracts();
+            match tracking_copy.borrow_mut().get_system_module(
+                correlation_id,
+                contract_wasm_hash,
+                use_system_contracts,
+                &preprocessor,
+            ) {
+                Ok(module) => module,
+                Err(error) => {
+                    return Ok((None, ExecutionResult::precondition_failure(error.into())));
+                }
+            }
+        };
+
+        let system_account = Account::new(
+            SYSTEM_ACCOUNT_ADDR,
+            Default::default(),
+            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
+            Default::default(),
+            Default::default(),
+        );
+        let mut authorization_keys = BTreeSet::new();
+        authorization_keys.insert(SYSTEM_ACCOUNT_ADDR);
+
+        let executor = Executor::new(self.config);
+        let mut named_keys = mint_contract.named_keys().to_owned();
+        let extra_keys = [Key::from(source), Key::from(target)];
+        let base_key = Key::from(protocol_data.mint());
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+
+        let runtime_args = runtime_args! {
+            ARG_SOURCE => source,
+            ARG_TARGET => target,
+            ARG_AMOUNT => amount,
+        };
+
+        Ok(executor.exec_system_contract(
+            DirectSystemContractCall::Transfer,
+            mint_module,
+            runtime_args,
+            &mut named_keys,
+            &extra_keys,
+            base_key,
+            &system_account,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::Session,
+            protocol_data,
+            SystemContractCache::clone(&self.system_contract_cache),
+        ))
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn deploy(
         &self,
@@ -1185,6 +1323,7 @@ where
         prestate_hash: Blake2bHash,
         blocktime: BlockTime,
         deploy_item: DeployItem,
+        gas_limit: Option<Gas>,
     ) -> Result<ExecutionResult, RootNotFound> {
         // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
 
@@ -1681,6 +1820,10 @@ where
             let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, CONV_RATE)
                 .unwrap_or_default()
                 - payment_result_cost;
+            let session_gas_limit = match gas_limit {
+                Some(gas_limit) => cmp::min(session_gas_limit, gas_limit),
+                None => session_gas_limit,
+            };
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
             executor.exec(
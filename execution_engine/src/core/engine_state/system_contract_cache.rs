@@ -33,6 +33,12 @@ impl SystemContractCache {
         let guarded_map = self.0.read().unwrap();
         guarded_map.get(&contract_hash).cloned()
     }
+
+    /// Removes every contract from the cache.
+    pub fn clear(&self) {
+        let mut guarded_map = self.0.write().unwrap();
+        guarded_map.clear()
+    }
 }
 
 #[cfg(test)]
@@ -124,6 +130,21 @@ mod tests {
         assert!(cache.has(reference))
     }
 
+    #[test]
+    fn should_clear() {
+        let cache = SystemContractCache::default();
+        let reference = {
+            let mut address_generator = ADDRESS_GENERATOR.lock().unwrap();
+            address_generator.create_address()
+        };
+        let module = Module::default();
+
+        cache.insert(reference, module);
+        cache.clear();
+
+        assert!(!cache.has(reference))
+    }
+
     #[test]
     fn should_get_none() {
         let reference = {
@@ -76,6 +76,8 @@ pub enum Error {
     HostBufferEmpty,
     #[error("Unsupported WASM start")]
     UnsupportedWasmStart,
+    #[error("Export name {} is reserved", _0)]
+    ReservedExportName(String),
     #[error("No active contract versions for contract package")]
     NoActiveContractVersions(ContractPackageHash),
     #[error("Invalid contract version: {}", _0)]
@@ -86,6 +88,8 @@ pub enum Error {
     WasmPreprocessing(wasm_prep::PreprocessingError),
     #[error("Unexpected Key length. Expected length {expected} but actual length is {actual}")]
     InvalidKeyLength { expected: usize, actual: usize },
+    #[error("Runtime stack overflow")]
+    RuntimeStackOverflow,
 }
 
 impl From<wasm_prep::PreprocessingError> for Error {
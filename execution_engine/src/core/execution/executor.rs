@@ -6,7 +6,6 @@ use std::{
 
 use parity_wasm::elements::Module;
 use tracing::warn;
-use wasmi::ModuleRef;
 
 use casper_types::{
     account::AccountHash, bytesrepr::FromBytes, contracts::NamedKeys, AccessRights, BlockTime,
@@ -23,7 +22,7 @@ use crate::{
         execution::{address_generator::AddressGenerator, Error},
         runtime::{
             extract_access_rights_from_keys, extract_access_rights_from_urefs, instance_and_memory,
-            Runtime,
+            invoke_export, Instance, Runtime,
         },
         runtime_context::{self, RuntimeContext},
         tracking_copy::TrackingCopy,
@@ -243,7 +242,7 @@ impl Executor {
         }
 
         on_fail_charge!(
-            instance.invoke_export(entry_point_name, &[], &mut runtime),
+            invoke_export(&instance, entry_point_name, &mut runtime),
             runtime.context().gas_counter(),
             effects_snapshot
         );
@@ -359,9 +358,9 @@ impl Executor {
         }
 
         let (maybe_ret, maybe_error, revert_effect): (Option<T>, Option<Error>, bool) = {
-            match instance.invoke_export(
+            match invoke_export(
+                &instance,
                 direct_system_contract_call.entry_point_name(),
-                &[],
                 &mut runtime,
             ) {
                 Err(error) => match error.as_host_error() {
@@ -398,7 +397,11 @@ impl Executor {
 
         let runtime_context = runtime.context();
 
-        let cost = runtime_context.gas_counter();
+        let cost = if self.config.zero_system_contract_gas() {
+            Gas::default()
+        } else {
+            runtime_context.gas_counter()
+        };
 
         let effect = if revert_effect {
             effect_snapshot
@@ -472,12 +475,11 @@ impl Executor {
             system_contract_cache,
         )?;
 
-        let error: wasmi::Error = match instance.invoke_export(entry_point_name, &[], &mut runtime)
-        {
+        let error: wasmi::Error = match invoke_export(&instance, entry_point_name, &mut runtime) {
             Err(error) => error,
             Ok(_) => {
                 // This duplicates the behavior of runtime sub_call.
-                // If `instance.invoke_export` returns `Ok` and the `host_buffer` is `None`, the
+                // If `invoke_export` returns `Ok` and the `host_buffer` is `None`, the
                 // contract's execution succeeded but did not explicitly call `runtime::ret()`.
                 // Treat as though the execution returned the unit type `()` as per Rust
                 // functions which don't specify a return value.
@@ -526,7 +528,7 @@ impl Executor {
         phase: Phase,
         protocol_data: ProtocolData,
         system_contract_cache: SystemContractCache,
-    ) -> Result<(ModuleRef, Runtime<'a, R>), Error>
+    ) -> Result<(Instance, Runtime<'a, R>), Error>
     where
         R: StateReader<Key, StoredValue>,
         R::Error: Into<Error>,
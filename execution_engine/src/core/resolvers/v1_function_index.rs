@@ -49,6 +49,16 @@ pub enum FunctionIndex {
     RemoveContractUserGroupIndex,
     ExtendContractUserGroupURefsIndex,
     RemoveContractUserGroupURefsIndex,
+    GetRefundPurseIndex,
+    CallerIsSystemContractIndex,
+    CurrentContractHashIndex,
+    ContractInCallStackIndex,
+    Keccak256Index,
+    GetContractPackageVersionsIndex,
+    GetEraIdIndex,
+    CallerIsSessionOfAccountIndex,
+    GetRemainingGasIndex,
+    AddContractVersionWithNamedKeysMigrationIndex,
 }
 
 impl Into<usize> for FunctionIndex {
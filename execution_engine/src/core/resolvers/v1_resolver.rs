@@ -204,6 +204,46 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
                 FunctionIndex::RemoveContractUserGroupURefsIndex.into(),
             ),
+            "get_refund_purse" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetRefundPurseIndex.into(),
+            ),
+            "caller_is_system_contract" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::CallerIsSystemContractIndex.into(),
+            ),
+            "current_contract_hash" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
+                FunctionIndex::CurrentContractHashIndex.into(),
+            ),
+            "contract_in_call_stack" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
+                FunctionIndex::ContractInCallStackIndex.into(),
+            ),
+            "keccak256" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::Keccak256Index.into(),
+            ),
+            "get_contract_package_versions" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
+                FunctionIndex::GetContractPackageVersionsIndex.into(),
+            ),
+            "get_era_id" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetEraIdIndex.into(),
+            ),
+            "caller_is_session_of_account" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
+                FunctionIndex::CallerIsSessionOfAccountIndex.into(),
+            ),
+            "get_remaining_gas" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], None),
+                FunctionIndex::GetRemainingGasIndex.into(),
+            ),
+            "add_contract_version_with_named_keys_migration" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 12][..], Some(ValueType::I32)),
+                FunctionIndex::AddContractVersionWithNamedKeysMigrationIndex.into(),
+            ),
             #[cfg(feature = "test-support")]
             "print" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 2][..], None),
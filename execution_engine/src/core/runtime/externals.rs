@@ -5,8 +5,8 @@ use wasmi::{Externals, RuntimeArgs, RuntimeValue, Trap};
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, ToBytes},
-    contracts::{EntryPoints, NamedKeys},
+    bytesrepr::{self, ToBytes, U64_SERIALIZED_LENGTH},
+    contracts::{EntryPoints, NamedKeys, NamedKeysMigration},
     ContractHash, ContractPackageHash, ContractVersion, Group, Key, TransferredTo, URef, U512,
 };
 
@@ -29,7 +29,11 @@ where
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
         let mut scoped_instrumenter = ScopedInstrumenter::new(func);
-        match func {
+        if self.config.execution_journal() {
+            scoped_instrumenter.record_into(self.context.state().borrow().journal());
+        }
+        let gas_before = self.context.gas_counter();
+        let result = match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
                 // args(1) = size of key in Wasm memory
@@ -458,6 +462,7 @@ where
                     contract_package_hash,
                     entry_points,
                     named_keys,
+                    NamedKeysMigration::AppendAll,
                     output_ptr,
                     output_size as usize,
                     bytes_written_ptr,
@@ -668,6 +673,167 @@ where
                 )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
-        }
+
+            FunctionIndex::GetRefundPurseIndex => {
+                // args(0) = pointer to a value where the size of the result will be written
+                let output_size_ptr = Args::parse(args)?;
+                self.charge_host_function(0)?;
+                scoped_instrumenter.pause();
+                let ret = self.get_refund_purse_host_buffer(output_size_ptr)?;
+                scoped_instrumenter.unpause();
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CallerIsSystemContractIndex => {
+                // args(0) = system contract index
+                let system_contract_index: u32 = Args::parse(args)?;
+                scoped_instrumenter.add_property("system_contract_index", system_contract_index);
+                self.charge_host_function(0)?;
+                Ok(Some(RuntimeValue::I32(i32::from(
+                    self.caller_is_system_contract(system_contract_index),
+                ))))
+            }
+
+            FunctionIndex::CurrentContractHashIndex => {
+                // args(0) = pointer to package hash in wasm memory
+                // args(1) = size of package hash in wasm memory
+                // args(2) = pointer to result size (output)
+                let (package_hash_ptr, package_hash_size, result_size_ptr) = Args::parse(args)?;
+                self.charge_host_function(package_hash_size)?;
+
+                let contract_package_hash = self.t_from_mem(package_hash_ptr, package_hash_size)?;
+                let result =
+                    self.current_contract_hash_host_buffer(contract_package_hash, result_size_ptr)?;
+
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
+
+            FunctionIndex::ContractInCallStackIndex => {
+                // args(0) = pointer to contract hash in wasm memory
+                // args(1) = size of contract hash in wasm memory
+                let (contract_hash_ptr, contract_hash_size) = Args::parse(args)?;
+                self.charge_host_function(contract_hash_size)?;
+
+                let contract_hash = self.t_from_mem(contract_hash_ptr, contract_hash_size)?;
+
+                Ok(Some(RuntimeValue::I32(i32::from(
+                    self.contract_in_call_stack(contract_hash),
+                ))))
+            }
+
+            FunctionIndex::Keccak256Index => {
+                // args(0) = pointer to input bytes in Wasm memory
+                // args(1) = size of input bytes
+                // args(2) = pointer to output buffer for the digest
+                // args(3) = size of output buffer
+                let (input_ptr, input_size, output_ptr, output_size): (u32, u32, u32, u32) =
+                    Args::parse(args)?;
+                scoped_instrumenter.add_property("input_size", input_size);
+                self.charge_host_function(input_size)?;
+                let ret =
+                    self.keccak256(input_ptr, input_size, output_ptr, output_size as usize)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetContractPackageVersionsIndex => {
+                // args(0) = pointer to package hash in wasm memory
+                // args(1) = size of package hash in wasm memory
+                // args(2) = pointer to result size (output)
+                let (package_hash_ptr, package_hash_size, result_size_ptr) = Args::parse(args)?;
+                self.charge_host_function(package_hash_size)?;
+
+                let contract_package_hash = self.t_from_mem(package_hash_ptr, package_hash_size)?;
+                let result = self.get_contract_package_versions_host_buffer(
+                    contract_package_hash,
+                    result_size_ptr,
+                )?;
+
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
+
+            FunctionIndex::GetEraIdIndex => {
+                // args(0) = pointer to result size (output)
+                let result_size_ptr = Args::parse(args)?;
+                self.charge_host_function(0)?;
+                let result = self.get_era_id_host_buffer(result_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
+
+            FunctionIndex::CallerIsSessionOfAccountIndex => {
+                // args(0) = pointer to account hash in wasm memory
+                // args(1) = size of account hash in wasm memory
+                let (account_hash_ptr, account_hash_size) = Args::parse(args)?;
+                self.charge_host_function(account_hash_size)?;
+
+                let account_hash = self.t_from_mem(account_hash_ptr, account_hash_size)?;
+
+                Ok(Some(RuntimeValue::I32(i32::from(
+                    self.caller_is_session_of_account(account_hash),
+                ))))
+            }
+
+            FunctionIndex::GetRemainingGasIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(U64_SERIALIZED_LENGTH as u32)?;
+                self.get_remaining_gas(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::AddContractVersionWithNamedKeysMigrationIndex => {
+                // args(0) = pointer to package key in wasm memory
+                // args(1) = size of package key in wasm memory
+                // args(2) = pointer to the output version in wasm memory
+                // args(3) = pointer to entrypoints in wasm memory
+                // args(4) = size of entrypoints in wasm memory
+                // args(5) = pointer to named keys in wasm memory
+                // args(6) = size of named keys in wasm memory
+                // args(7) = pointer to named keys migration in wasm memory
+                // args(8) = size of named keys migration in wasm memory
+                // args(9) = pointer to output buffer for serialized key
+                // args(10) = size of output buffer
+                // args(11) = pointer to bytes written
+                let (
+                    contract_package_hash_ptr,
+                    contract_package_hash_size,
+                    version_ptr,
+                    entry_points_ptr,
+                    entry_points_size,
+                    named_keys_ptr,
+                    named_keys_size,
+                    named_keys_migration_ptr,
+                    named_keys_migration_size,
+                    output_ptr,
+                    output_size,
+                    bytes_written_ptr,
+                ): (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32) =
+                    Args::parse(args)?;
+
+                scoped_instrumenter
+                    .add_property("entry_points_size", entry_points_size.to_string());
+                scoped_instrumenter.add_property("named_keys_size", named_keys_size.to_string());
+
+                let contract_package_hash: ContractPackageHash =
+                    self.t_from_mem(contract_package_hash_ptr, contract_package_hash_size)?;
+                let entry_points: EntryPoints =
+                    self.t_from_mem(entry_points_ptr, entry_points_size)?;
+                let named_keys: NamedKeys = self.t_from_mem(named_keys_ptr, named_keys_size)?;
+                let named_keys_migration: NamedKeysMigration =
+                    self.t_from_mem(named_keys_migration_ptr, named_keys_migration_size)?;
+                let ret = self.add_contract_version(
+                    contract_package_hash,
+                    entry_points,
+                    named_keys,
+                    named_keys_migration,
+                    output_ptr,
+                    output_size as usize,
+                    bytes_written_ptr,
+                    version_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+        };
+        scoped_instrumenter.set_gas(self.context.gas_counter() - gas_before);
+        result
     }
 }
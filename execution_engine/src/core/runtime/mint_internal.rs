@@ -63,7 +63,7 @@ where
             .map_err(|_| Error::Storage)?;
         match maybe_value {
             Some(value) => {
-                let value = CLValue::into_t(value).unwrap();
+                let value = CLValue::into_t(value).map_err(|_| Error::Storage)?;
                 Ok(Some(value))
             }
             None => Ok(None),
@@ -77,10 +77,10 @@ where
             .map_err(|_| Error::Storage)?;
         match maybe_value {
             Some(StoredValue::CLValue(value)) => {
-                let value = CLValue::into_t(value).unwrap();
+                let value = CLValue::into_t(value).map_err(|_| Error::Storage)?;
                 Ok(Some(value))
             }
-            Some(error) => panic!("should have received value: {:?}", error),
+            Some(_) => Err(Error::Storage),
             None => Ok(None),
         }
     }
@@ -7,42 +7,51 @@ mod scoped_instrumenter;
 mod standard_payment_internal;
 
 use std::{
+    cell::RefCell,
     cmp,
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     convert::TryFrom,
     iter::IntoIterator,
+    rc::Rc,
 };
 
 use itertools::Itertools;
-use parity_wasm::elements::Module;
-use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
+use parity_wasm::elements::{ExportEntry, ExportSection, Internal, Module, Section};
+use tiny_keccak::{Hasher, Keccak};
+use wasmi::{
+    Externals, ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, RuntimeValue, Trap, TrapKind,
+};
 
 use casper_types::{
     account::{AccountHash, ActionType, Weight},
-    auction::{self, Auction},
+    auction::{self, Auction, EraId},
     bytesrepr::{self, FromBytes, ToBytes},
     contracts::{
         self, Contract, ContractPackage, ContractVersion, ContractVersions, DisabledVersions,
-        EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys,
+        EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys, NamedKeysMigration,
     },
     mint::{self, Mint},
     proof_of_stake::{self, ProofOfStake},
     runtime_args, standard_payment,
     standard_payment::StandardPayment,
     system_contract_errors, AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash,
-    ContractPackageHash, ContractVersionKey, ContractWasm, EntryPointType, Key, ProtocolVersion,
-    RuntimeArgs, SystemContractType, TransferResult, TransferredTo, URef, U128, U256, U512,
+    ContractPackageHash, ContractVersionKey, ContractWasm, ContractWasmHash, EntryPointType, Key,
+    ProtocolVersion, RuntimeArgs, SystemContractType, TransferResult, TransferredTo, URef,
+    KECCAK256_DIGEST_LENGTH, U128, U256, U512,
 };
 
 use crate::{
     core::{
-        engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
+        engine_state::{
+            execution_journal::JournalEntry, system_contract_cache::SystemContractCache,
+            EngineConfig,
+        },
         execution::Error,
         resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
         runtime_context::{self, RuntimeContext},
         Address,
     },
-    shared::{account::Account, gas::Gas, stored_value::StoredValue},
+    shared::{account::Account, gas::Gas, stored_value::StoredValue, TypeMismatch},
     storage::{global_state::StateReader, protocol_data::ProtocolData},
 };
 use scoped_instrumenter::ScopedInstrumenter;
@@ -54,6 +63,10 @@ pub struct Runtime<'a, R> {
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
+    /// Base keys of the contexts which called into this one, outermost first.
+    call_stack: Vec<Key>,
+    /// Modules of the contracts called so far during this execution, keyed by wasm hash.
+    module_cache: Rc<RefCell<HashMap<ContractWasmHash, Module>>>,
 }
 
 /// Rename function called `name` in the `module` to `call`.
@@ -72,23 +85,108 @@ pub fn rename_export_to_call(module: &mut Module, name: String) {
     main_export.push_str("call");
 }
 
+/// Name under which the `start` function of a module is exported, so that it can be run by
+/// [`invoke_export`] once the host [`Runtime`] for the module exists.  Modules may not export
+/// anything under this name themselves.
+const START_FUNCTION_EXPORT: &str = "__casper_start";
+
+/// Replaces the `start` section of the `module`, if any, with an export of the same function
+/// named [`START_FUNCTION_EXPORT`].  Returns `true` if the module had a `start` section.
+fn export_start_function(module: &mut Module) -> Result<bool, Error> {
+    if let Some(export_section) = module.export_section() {
+        if export_section
+            .entries()
+            .iter()
+            .any(|export| export.field() == START_FUNCTION_EXPORT)
+        {
+            return Err(Error::ReservedExportName(START_FUNCTION_EXPORT.to_string()));
+        }
+    }
+
+    let start_function_index = match module.start_section() {
+        Some(start_function_index) => start_function_index,
+        None => return Ok(false),
+    };
+    module.clear_start_section();
+
+    let start_export = ExportEntry::new(
+        START_FUNCTION_EXPORT.to_string(),
+        Internal::Function(start_function_index),
+    );
+    match module.export_section_mut() {
+        Some(export_section) => export_section.entries_mut().push(start_export),
+        None => module
+            .insert_section(Section::Export(ExportSection::with_entries(vec![
+                start_export,
+            ])))
+            .map_err(Error::ParityWasm)?,
+    }
+    Ok(true)
+}
+
+/// An instantiated module, which remembers whether it had a `start` function that has to run
+/// before any of its exports.
+pub struct Instance {
+    module_ref: ModuleRef,
+    has_start_function: bool,
+}
+
 pub fn instance_and_memory(
-    parity_module: Module,
+    mut parity_module: Module,
     protocol_version: ProtocolVersion,
-) -> Result<(ModuleRef, MemoryRef), Error> {
+) -> Result<(Instance, MemoryRef), Error> {
+    let has_start_function = export_start_function(&mut parity_module)?;
     let module = wasmi::Module::from_parity_wasm_module(parity_module)?;
     let resolver = create_module_resolver(protocol_version)?;
     let mut imports = ImportsBuilder::new();
     imports.push_resolver("env", &resolver);
     let not_started_module = ModuleInstance::new(&module, &imports)?;
-    if not_started_module.has_start() {
-        return Err(Error::UnsupportedWasmStart);
-    }
-    let instance = not_started_module.not_started_instance().clone();
+    let module_ref = not_started_module.assert_no_start();
     let memory = resolver.memory_ref()?;
+    let instance = Instance {
+        module_ref,
+        has_start_function,
+    };
     Ok((instance, memory))
 }
 
+/// Invokes the export called `name` of the `instance`, first running the module's `start`
+/// function if it had one.
+///
+/// The `start` function is run with the same `externals` as the export, so it is charged against
+/// the same gas limit.  It can't be invoked as an export on its own.
+pub fn invoke_export<E: Externals>(
+    instance: &Instance,
+    name: &str,
+    externals: &mut E,
+) -> Result<Option<RuntimeValue>, wasmi::Error> {
+    if name == START_FUNCTION_EXPORT {
+        return Err(wasmi::Error::Function(format!(
+            "Module doesn't have export {}",
+            name
+        )));
+    }
+    if instance.has_start_function {
+        instance
+            .module_ref
+            .invoke_export(START_FUNCTION_EXPORT, &[], externals)?;
+    }
+    instance.module_ref.invoke_export(name, &[], externals)
+}
+
+#[cfg(feature = "test-support")]
+thread_local! {
+    /// Text printed through the `print` host function on the current thread.
+    static CAPTURED_OUTPUT: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// Returns the text printed through the `print` host function on the current thread since the
+/// last call, and clears it.
+#[cfg(feature = "test-support")]
+pub fn take_captured_output() -> Vec<String> {
+    CAPTURED_OUTPUT.with(|captured_output| captured_output.borrow_mut().split_off(0))
+}
+
 /// Turns `key` into a `([u8; 32], AccessRights)` tuple.
 /// Returns None if `key` is not `Key::URef` as it wouldn't have `AccessRights`
 /// associated with it. Helper function for creating `named_keys` associating
@@ -140,6 +238,21 @@ pub fn extract_access_rights_from_keys<I: IntoIterator<Item = Key>>(
         .collect()
 }
 
+/// Checks if `key` refers to the system contract of the given type according to `protocol_data`.
+fn is_system_contract(
+    protocol_data: &ProtocolData,
+    key: Key,
+    system_contract_type: SystemContractType,
+) -> bool {
+    let contract_hash = match system_contract_type {
+        SystemContractType::Mint => protocol_data.mint(),
+        SystemContractType::ProofOfStake => protocol_data.proof_of_stake(),
+        SystemContractType::StandardPayment => protocol_data.standard_payment(),
+        SystemContractType::Auction => protocol_data.auction(),
+    };
+    key.into_seed() == contract_hash
+}
+
 #[allow(clippy::cognitive_complexity)]
 fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
     match cl_value.cl_type() {
@@ -1343,8 +1456,61 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
             }
             (_, _) => Ok(vec![]),
         },
-        // TODO: nested matches for Tuple3?
-        CLType::Tuple3(_) => Ok(vec![]),
+        CLType::Tuple3([ty1, ty2, ty3]) => match (&**ty1, &**ty2, &**ty3) {
+            (CLType::URef, CLType::URef, CLType::URef) => {
+                let val: (URef, URef, URef) = cl_value.to_owned().into_t()?;
+                Ok(vec![val.0, val.1, val.2])
+            }
+            (CLType::URef, CLType::URef, CLType::Key) => {
+                let val: (URef, URef, Key) = cl_value.to_owned().into_t()?;
+                let mut res = vec![val.0, val.1];
+                res.extend(val.2.into_uref().into_iter());
+                Ok(res)
+            }
+            (CLType::URef, CLType::Key, CLType::URef) => {
+                let val: (URef, Key, URef) = cl_value.to_owned().into_t()?;
+                let mut res = vec![val.0];
+                res.extend(val.1.into_uref().into_iter());
+                res.push(val.2);
+                Ok(res)
+            }
+            (CLType::URef, CLType::Key, CLType::Key) => {
+                let val: (URef, Key, Key) = cl_value.to_owned().into_t()?;
+                let mut res = vec![val.0];
+                res.extend(val.1.into_uref().into_iter());
+                res.extend(val.2.into_uref().into_iter());
+                Ok(res)
+            }
+            (CLType::Key, CLType::URef, CLType::URef) => {
+                let val: (Key, URef, URef) = cl_value.to_owned().into_t()?;
+                let mut res: Vec<URef> = val.0.into_uref().into_iter().collect();
+                res.push(val.1);
+                res.push(val.2);
+                Ok(res)
+            }
+            (CLType::Key, CLType::URef, CLType::Key) => {
+                let val: (Key, URef, Key) = cl_value.to_owned().into_t()?;
+                let mut res: Vec<URef> = val.0.into_uref().into_iter().collect();
+                res.push(val.1);
+                res.extend(val.2.into_uref().into_iter());
+                Ok(res)
+            }
+            (CLType::Key, CLType::Key, CLType::URef) => {
+                let val: (Key, Key, URef) = cl_value.to_owned().into_t()?;
+                let mut res: Vec<URef> = val.0.into_uref().into_iter().collect();
+                res.extend(val.1.into_uref().into_iter());
+                res.push(val.2);
+                Ok(res)
+            }
+            (CLType::Key, CLType::Key, CLType::Key) => {
+                let val: (Key, Key, Key) = cl_value.to_owned().into_t()?;
+                let mut res: Vec<URef> = val.0.into_uref().into_iter().collect();
+                res.extend(val.1.into_uref().into_iter());
+                res.extend(val.2.into_uref().into_iter());
+                Ok(res)
+            }
+            (_, _, _) => Ok(vec![]),
+        },
         CLType::Key => {
             let key: Key = cl_value.to_owned().into_t()?; // TODO: optimize?
             Ok(key.into_uref().into_iter().collect())
@@ -1375,6 +1541,8 @@ where
             module,
             host_buffer: None,
             context,
+            call_stack: Vec::new(),
+            module_cache: Default::default(),
         }
     }
 
@@ -1420,6 +1588,16 @@ where
         }
     }
 
+    /// Charges for a host function call which processes `bytes` bytes of Wasm memory: the cost of
+    /// a regular opcode plus the `memcpy` cost for each byte, both taken from the current
+    /// `WasmCosts`.
+    fn charge_host_function(&mut self, bytes: u32) -> Result<(), Trap> {
+        let wasm_costs = *self.protocol_data().wasm_costs();
+        let cost =
+            U512::from(wasm_costs.regular) + U512::from(wasm_costs.memcpy) * U512::from(bytes);
+        self.gas(Gas::new(cost))
+    }
+
     fn bytes_from_mem(&self, ptr: u32, size: usize) -> Result<Vec<u8>, Error> {
         self.memory.get(ptr, size).map_err(Into::into)
     }
@@ -1609,6 +1787,24 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Writes the gas remaining before the gas limit of the current execution is reached to
+    /// [dest_ptr] in Wasm memory.
+    ///
+    /// The value saturates at `u64::MAX`.  It is read after the host function has charged for
+    /// itself, so it is the gas actually left for the rest of the execution.
+    fn get_remaining_gas(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let remaining_gas = self
+            .context
+            .gas_limit()
+            .value()
+            .saturating_sub(self.context.gas_counter().value());
+        let remaining_gas = cmp::min(remaining_gas, U512::from(u64::max_value())).as_u64();
+        let remaining_gas = remaining_gas.into_bytes().map_err(Error::BytesRepr)?;
+        self.memory
+            .set(dest_ptr, &remaining_gas)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
     /// Return some bytes from the memory and terminate the current `sub_call`. Note that the return
     /// type is `Trap`, indicating that this function will always kill the current Wasm instance.
     fn ret(
@@ -1765,6 +1961,14 @@ where
                 let amount: U512 = Self::get_named_argument(&runtime_args, mint::ARG_AMOUNT)?;
                 let result: Result<(), system_contract_errors::mint::Error> =
                     mint_runtime.transfer(source, target, amount);
+                if result.is_ok() && self.config.execution_journal() {
+                    self.context
+                        .state()
+                        .borrow()
+                        .journal()
+                        .borrow_mut()
+                        .push(JournalEntry::transfer(source, target, amount));
+                }
                 CLValue::from_t(result).map_err(Self::reverter)?
             }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
@@ -2147,15 +2351,17 @@ where
 
         self.validate_entry_point_access(&contract_package, entry_point.access())?;
 
-        for (expected, found) in entry_point
-            .args()
-            .iter()
-            .map(|a| a.cl_type())
-            .cloned()
-            .zip(args.to_values().into_iter().map(|v| v.cl_type()).cloned())
-        {
-            if expected != found {
-                return Err(Error::type_mismatch(expected, found));
+        if self.config.strict_argument_checking() {
+            for (expected, found) in entry_point
+                .args()
+                .iter()
+                .map(|a| a.cl_type())
+                .cloned()
+                .zip(args.to_values().into_iter().map(|v| v.cl_type()).cloned())
+            {
+                if expected != found {
+                    return Err(Error::type_mismatch(expected, found));
+                }
             }
         }
 
@@ -2216,6 +2422,15 @@ where
             });
         }
 
+        // Check the height of the call stack before descending into another contract.  The current
+        // frame isn't part of `call_stack`, which only holds its callers.
+        if let Some(max_height) = self.config.max_runtime_call_stack_height() {
+            let current_height = self.call_stack.len() + 1;
+            if current_height >= max_height as usize {
+                return Err(Error::RuntimeStackOverflow);
+            }
+        }
+
         // TODO: should we be using named_keys_mut() instead?
         let mut named_keys = match entry_point.entry_point_type() {
             EntryPointType::Session => self.context.account().named_keys().clone(),
@@ -2274,7 +2489,11 @@ where
         };
 
         let module = {
-            let maybe_module = self.system_contract_cache.get(key.into_seed());
+            let contract_wasm_hash = contract.contract_wasm_hash();
+            let maybe_module = self
+                .system_contract_cache
+                .get(key.into_seed())
+                .or_else(|| self.module_cache.borrow().get(&contract_wasm_hash).cloned());
             let wasm_key = contract.contract_wasm_key();
 
             let contract_wasm: ContractWasm = match self.context.read_gs(&wasm_key)? {
@@ -2289,7 +2508,15 @@ where
             };
             match maybe_module {
                 Some(module) => module,
-                None => parity_wasm::deserialize_buffer(contract_wasm.bytes())?,
+                None => {
+                    // Wasm stored under a given hash never changes, so the parsed module can be
+                    // reused for the rest of this execution.
+                    let module: Module = parity_wasm::deserialize_buffer(contract_wasm.bytes())?;
+                    self.module_cache
+                        .borrow_mut()
+                        .insert(contract_wasm_hash, module.clone());
+                    module
+                }
             }
         };
 
@@ -2332,6 +2559,9 @@ where
             self.context.protocol_data(),
         );
 
+        let mut call_stack = self.call_stack.clone();
+        call_stack.push(self.context.base_key());
+
         let mut runtime = Runtime {
             system_contract_cache,
             config,
@@ -2339,14 +2569,20 @@ where
             module,
             host_buffer,
             context,
+            call_stack,
+            module_cache: Rc::clone(&self.module_cache),
         };
 
-        let result = instance.invoke_export(entry_point_name, &[], &mut runtime);
+        let result = invoke_export(&instance, entry_point_name, &mut runtime);
 
         // The `runtime`'s context was initialized with our counter from before the call and any gas
         // charged by the sub-call was added to its counter - so let's copy the correct value of the
-        // counter from there to our counter
-        self.context.set_gas_counter(runtime.context.gas_counter());
+        // counter from there to our counter, unless system contract calls are configured to be free
+        let is_system_contract =
+            self.is_mint(key) || self.is_proof_of_stake(key) || self.is_auction(key);
+        if !(is_system_contract && self.config.zero_system_contract_gas()) {
+            self.context.set_gas_counter(runtime.context.gas_counter());
+        }
 
         let error = match result {
             Err(error) => error,
@@ -2468,6 +2704,102 @@ where
         Ok(Ok(()))
     }
 
+    /// Queries the Proof of Stake contract for the refund purse of the current deploy and writes
+    /// the result, serialized as `Option<URef>`, to the host buffer.
+    fn get_refund_purse_host_buffer(
+        &mut self,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        // Exit early if the host buffer is already occupied
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+        let pos_contract_hash = self.get_pos_contract();
+        let result = self.call_contract(
+            pos_contract_hash,
+            proof_of_stake::METHOD_GET_REFUND_PURSE,
+            RuntimeArgs::new(),
+        )?;
+        self.manage_call_contract_host_buffer(result_size_ptr, result)
+    }
+
+    /// Writes the hash of the newest enabled contract version of the given package, serialized as
+    /// `Option<ContractHash>`, to the host buffer.
+    fn current_contract_hash_host_buffer(
+        &mut self,
+        contract_package_hash: ContractPackageHash,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        // Exit early if the host buffer is already occupied
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+        // Same checks as `RuntimeContext::get_validated_contract_package`, but a value of the wrong
+        // type is reported as a type mismatch.
+        let key = contract_package_hash.into();
+        self.context.validate_key(&key)?;
+        let contract_package = match self.context.read_gs(&key)? {
+            Some(StoredValue::ContractPackage(contract_package)) => contract_package,
+            Some(stored_value) => {
+                return Err(Error::TypeMismatch(TypeMismatch::new(
+                    "ContractPackage".to_string(),
+                    stored_value.type_name(),
+                )));
+            }
+            None => return Err(Error::KeyNotFound(key)),
+        };
+        self.context.validate_uref(&contract_package.access_key())?;
+        let result = CLValue::from_t(contract_package.current_contract_hash())?;
+        self.manage_call_contract_host_buffer(result_size_ptr, result)
+    }
+
+    fn get_contract_package_versions_host_buffer(
+        &mut self,
+        contract_package_hash: ContractPackageHash,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        // Exit early if the host buffer is already occupied
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+        let contract_package = self
+            .context
+            .get_validated_contract_package(contract_package_hash)?;
+        let result = CLValue::from_t(contract_package.enabled_versions())?;
+        self.manage_call_contract_host_buffer(result_size_ptr, result)
+    }
+
+    fn get_era_id_host_buffer(
+        &mut self,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        // Exit early if the host buffer is already occupied
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+        let result = CLValue::from_t(self.read_era_id()?)?;
+        self.manage_call_contract_host_buffer(result_size_ptr, result)
+    }
+
+    /// Reads the current era id from the named keys of the auction contract.
+    fn read_era_id(&mut self) -> Result<EraId, Error> {
+        let auction_contract: Contract = self
+            .context
+            .read_gs_typed(&self.get_auction_contract().into())?;
+        let era_id_key = auction_contract
+            .named_keys()
+            .get(auction::ERA_ID_KEY)
+            .cloned()
+            .ok_or_else(|| Error::NamedKeyNotFound(auction::ERA_ID_KEY.to_string()))?;
+        match self.context.read_gs_direct(&era_id_key)? {
+            Some(stored_value) => {
+                let cl_value = CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?;
+                Ok(cl_value.into_t()?)
+            }
+            None => Err(Error::KeyNotFound(era_id_key)),
+        }
+    }
+
     fn load_named_keys(
         &mut self,
         total_keys_ptr: u32,
@@ -2618,6 +2950,7 @@ where
         contract_package_hash: ContractPackageHash,
         entry_points: EntryPoints,
         mut named_keys: NamedKeys,
+        named_keys_migration: NamedKeysMigration,
         output_ptr: u32,
         output_size: usize,
         bytes_written_ptr: u32,
@@ -2643,13 +2976,11 @@ where
         let protocol_version = self.context.protocol_version();
         let major = protocol_version.value().major;
 
-        // TODO: EE-1032 - Implement different ways of carrying on existing named keys
         if let Some(previous_contract_hash) = contract_package.current_contract_hash() {
             let previous_contract: Contract =
                 self.context.read_gs_typed(&previous_contract_hash.into())?;
 
-            let mut previous_named_keys = previous_contract.take_named_keys();
-            named_keys.append(&mut previous_named_keys);
+            named_keys_migration.apply(&mut named_keys, previous_contract.take_named_keys());
         }
 
         let contract = Contract::new(
@@ -3169,10 +3500,15 @@ where
 
         let uref_key = match self.context.read_ls(&key)? {
             Some(cl_value) => {
-                let key: Key = cl_value.into_t().expect("expected Key type");
+                let key: Key = cl_value.into_t()?;
                 match key {
                     Key::URef(_) => (),
-                    _ => panic!("expected Key::Uref(_)"),
+                    _ => {
+                        return Err(Error::TypeMismatch(TypeMismatch::new(
+                            "Key::URef".to_string(),
+                            format!("{:?}", key),
+                        )))
+                    }
                 }
                 key
             }
@@ -3180,15 +3516,11 @@ where
         };
 
         let ret = match self.context.read_gs_direct(&uref_key)? {
-            Some(StoredValue::CLValue(cl_value)) => {
-                if *cl_value.cl_type() == CLType::U512 {
-                    let balance: U512 = cl_value.into_t()?;
-                    Some(balance)
-                } else {
-                    panic!("expected U512")
-                }
+            Some(stored_value) => {
+                let cl_value = CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?;
+                let balance: U512 = cl_value.into_t()?;
+                Some(balance)
             }
-            Some(_) => panic!("expected U512"),
             None => None,
         };
 
@@ -3258,6 +3590,61 @@ where
         }
     }
 
+    /// Checks if the immediate caller of the currently executing contract is the system contract
+    /// identified by `system_contract_index`.
+    fn caller_is_system_contract(&self, system_contract_index: u32) -> bool {
+        let caller = match self.call_stack.last() {
+            Some(caller) => *caller,
+            None => return false,
+        };
+        match SystemContractType::try_from(system_contract_index) {
+            Ok(system_contract_type) => {
+                is_system_contract(&self.protocol_data(), caller, system_contract_type)
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Checks if the immediate caller of the currently executing contract is session code running
+    /// in the context of the given account.
+    ///
+    /// Session code, including a stored entry point of type `EntryPointType::Session`, runs with
+    /// the account's key as its base key, so both kinds of session frame match here.
+    fn caller_is_session_of_account(&self, account_hash: AccountHash) -> bool {
+        self.call_stack.last() == Some(&Key::Account(account_hash))
+    }
+
+    /// Checks if the given contract is one of the callers of the currently executing contract.
+    fn contract_in_call_stack(&self, contract_hash: ContractHash) -> bool {
+        self.call_stack.contains(&Key::from(contract_hash))
+    }
+
+    /// Writes the Keccak-256 digest of the given input to the output buffer.
+    fn keccak256(
+        &mut self,
+        input_ptr: u32,
+        input_size: u32,
+        output_ptr: u32,
+        output_size: usize,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if output_size != KECCAK256_DIGEST_LENGTH {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+
+        let input = self.bytes_from_mem(input_ptr, input_size as usize)?;
+
+        let mut digest = [0u8; KECCAK256_DIGEST_LENGTH];
+        let mut hasher = Keccak::v256();
+        hasher.update(&input);
+        hasher.finalize(&mut digest);
+
+        if let Err(error) = self.memory.set(output_ptr, &digest) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
     /// If host_buffer set, clears the host_buffer and returns value, else None
     pub fn take_host_buffer(&mut self) -> Option<CLValue> {
         self.host_buffer.take()
@@ -3318,6 +3705,7 @@ where
     fn print(&mut self, text_ptr: u32, text_size: u32) -> Result<(), Trap> {
         let text = self.string_from_mem(text_ptr, text_size)?;
         println!("{}", text);
+        CAPTURED_OUTPUT.with(|captured_output| captured_output.borrow_mut().push(text));
         Ok(())
     }
 
@@ -3559,9 +3947,12 @@ mod tests {
         result,
     };
 
-    use casper_types::{gens::*, AccessRights, CLType, CLValue, Key, URef};
+    use casper_types::{
+        gens::*, AccessRights, CLType, CLValue, ContractHash, Key, SystemContractType, URef,
+    };
 
-    use super::extract_urefs;
+    use super::{extract_urefs, is_system_contract};
+    use crate::{shared::wasm_costs::WasmCosts, storage::protocol_data::ProtocolData};
     use std::collections::BTreeMap;
 
     fn cl_value_with_urefs_arb() -> impl Strategy<Value = (CLValue, Vec<URef>)> {
@@ -3695,6 +4086,17 @@ mod tests {
                 urefs.extend(x.1.as_uref().into_iter().cloned());
                 (CLValue::from_t(x).expect("should create CLValue"), urefs)
             }),
+            (uref_arb(), uref_arb(), key_arb()).prop_map(|x| {
+                let mut urefs = vec![x.0, x.1];
+                urefs.extend(x.2.as_uref().into_iter().cloned());
+                (CLValue::from_t(x).expect("should create CLValue"), urefs)
+            }),
+            (key_arb(), uref_arb(), key_arb()).prop_map(|x| {
+                let mut urefs: Vec<URef> = x.0.as_uref().into_iter().cloned().collect();
+                urefs.push(x.1);
+                urefs.extend(x.2.as_uref().into_iter().cloned());
+                (CLValue::from_t(x).expect("should create CLValue"), urefs)
+            }),
         ]
     }
 
@@ -3724,4 +4126,44 @@ mod tests {
         let cl_value = CLValue::from_t(map).unwrap();
         assert_eq!(extract_urefs(&cl_value).unwrap(), vec![uref]);
     }
+
+    #[test]
+    fn should_match_system_contract_by_type() {
+        const MINT: ContractHash = [1; 32];
+        const PROOF_OF_STAKE: ContractHash = [2; 32];
+        const STANDARD_PAYMENT: ContractHash = [3; 32];
+        const AUCTION: ContractHash = [4; 32];
+
+        let protocol_data = ProtocolData::new(
+            WasmCosts::default(),
+            MINT,
+            PROOF_OF_STAKE,
+            STANDARD_PAYMENT,
+            AUCTION,
+        );
+        let system_contracts = [
+            (SystemContractType::Mint, MINT),
+            (SystemContractType::ProofOfStake, PROOF_OF_STAKE),
+            (SystemContractType::StandardPayment, STANDARD_PAYMENT),
+            (SystemContractType::Auction, AUCTION),
+        ];
+
+        for (system_contract_type, _) in system_contracts.iter() {
+            for (other_type, contract_hash) in system_contracts.iter() {
+                assert_eq!(
+                    is_system_contract(
+                        &protocol_data,
+                        Key::Hash(*contract_hash),
+                        *system_contract_type
+                    ),
+                    system_contract_type == other_type
+                );
+            }
+            assert!(!is_system_contract(
+                &protocol_data,
+                Key::Hash([5; 32]),
+                *system_contract_type
+            ));
+        }
+    }
 }
@@ -1,11 +1,17 @@
 use std::{
+    cell::RefCell,
     collections::BTreeMap,
     mem,
+    rc::Rc,
     time::{Duration, Instant},
 };
 
 use crate::{
-    core::resolvers::v1_function_index::FunctionIndex, shared::logging::log_host_function_metrics,
+    core::{
+        engine_state::execution_journal::{ExecutionJournal, HostFunctionCall, JournalEntry},
+        resolvers::v1_function_index::FunctionIndex,
+    },
+    shared::{gas::Gas, logging::log_host_function_metrics},
 };
 
 enum PauseState {
@@ -51,6 +57,8 @@ pub(super) struct ScopedInstrumenter {
     pause_state: PauseState,
     function_index: FunctionIndex,
     properties: BTreeMap<&'static str, String>,
+    journal: Option<Rc<RefCell<ExecutionJournal>>>,
+    gas: Gas,
 }
 
 impl ScopedInstrumenter {
@@ -60,9 +68,21 @@ impl ScopedInstrumenter {
             pause_state: PauseState::new(),
             function_index,
             properties: BTreeMap::new(),
+            journal: None,
+            gas: Gas::default(),
         }
     }
 
+    /// Records this host function call, along with its properties, into `journal` when dropped.
+    pub(super) fn record_into(&mut self, journal: Rc<RefCell<ExecutionJournal>>) {
+        self.journal = Some(journal);
+    }
+
+    /// Sets the gas charged while the host function ran, to be recorded in the journal.
+    pub(super) fn set_gas(&mut self, gas: Gas) {
+        self.gas = gas;
+    }
+
     pub(super) fn add_property<T: ToString>(&mut self, key: &'static str, value: T) {
         assert!(self.properties.insert(key, value.to_string()).is_none());
     }
@@ -139,9 +159,40 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::RemoveContractUserGroupURefsIndex => {
                 "host_remove_contract_user_group_urefs"
             }
+            FunctionIndex::GetRefundPurseIndex => "host_function_get_refund_purse",
+            FunctionIndex::CallerIsSystemContractIndex => "host_function_caller_is_system_contract",
+            FunctionIndex::CurrentContractHashIndex => "host_function_current_contract_hash",
+            FunctionIndex::ContractInCallStackIndex => "host_function_contract_in_call_stack",
+            FunctionIndex::Keccak256Index => "host_function_keccak256",
+            FunctionIndex::GetContractPackageVersionsIndex => {
+                "host_function_get_contract_package_versions"
+            }
+            FunctionIndex::GetEraIdIndex => "host_function_get_era_id",
+            FunctionIndex::CallerIsSessionOfAccountIndex => {
+                "host_function_caller_is_session_of_account"
+            }
+            FunctionIndex::GetRemainingGasIndex => "host_function_get_remaining_gas",
+            FunctionIndex::AddContractVersionWithNamedKeysMigrationIndex => {
+                "host_function_add_contract_version_with_named_keys_migration"
+            }
         };
 
         let mut properties = mem::take(&mut self.properties);
+
+        if let Some(journal) = self.journal.take() {
+            let journal_properties = properties
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect();
+            journal
+                .borrow_mut()
+                .push(JournalEntry::HostFunction(HostFunctionCall::new(
+                    host_function.to_string(),
+                    journal_properties,
+                    self.gas.value(),
+                )));
+        }
+
         properties.insert(
             "duration_in_seconds",
             format!("{:.06e}", duration.as_secs_f64()),
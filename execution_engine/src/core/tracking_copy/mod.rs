@@ -5,9 +5,11 @@ pub(self) mod meter;
 mod tests;
 
 use std::{
+    cell::RefCell,
     collections::{HashMap, HashSet, VecDeque},
     convert::From,
     iter,
+    rc::Rc,
 };
 
 use linked_hash_map::LinkedHashMap;
@@ -17,7 +19,11 @@ use casper_types::{bytesrepr, CLType, CLValueError, Key};
 pub use self::ext::TrackingCopyExt;
 use self::meter::{heap_meter::HeapSize, Meter};
 use crate::{
-    core::engine_state::{execution_effect::ExecutionEffect, op::Op},
+    core::engine_state::{
+        execution_effect::ExecutionEffect,
+        execution_journal::{ExecutionJournal, JournalEntry},
+        op::Op,
+    },
     shared::{
         additive_map::AdditiveMap,
         newtypes::CorrelationId,
@@ -155,6 +161,8 @@ pub struct TrackingCopy<R> {
     cache: TrackingCopyCache<HeapSize>,
     ops: AdditiveMap<Key, Op>,
     fns: AdditiveMap<Key, Transform>,
+    journal: Rc<RefCell<ExecutionJournal>>,
+    record_journal: bool,
 }
 
 #[derive(Debug)]
@@ -188,9 +196,18 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
              * limit? */
             ops: AdditiveMap::new(),
             fns: AdditiveMap::new(),
+            journal: Rc::new(RefCell::new(ExecutionJournal::new())),
+            record_journal: false,
         }
     }
 
+    /// Sets whether reads, writes and adds are recorded in the execution journal of this tracking
+    /// copy and of any tracking copy forked from it.
+    pub fn with_execution_journal(mut self, record_journal: bool) -> Self {
+        self.record_journal = record_journal;
+        self
+    }
+
     pub fn reader(&self) -> &R {
         &self.reader
     }
@@ -208,7 +225,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
     /// forking, however we recognize this is sub-optimal and will revisit
     /// in the future.
     pub fn fork(&self) -> TrackingCopy<&TrackingCopy<R>> {
-        TrackingCopy::new(self)
+        TrackingCopy::new(self).with_execution_journal(self.record_journal)
     }
 
     pub fn get(
@@ -236,6 +253,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         if let Some(value) = self.get(correlation_id, &normalized_key)? {
             self.ops.insert_add(normalized_key, Op::Read);
             self.fns.insert_add(normalized_key, Transform::Identity);
+            self.record(JournalEntry::read(&normalized_key));
             Ok(Some(value))
         } else {
             Ok(None)
@@ -247,6 +265,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         self.cache.insert_write(normalized_key, value.clone());
         self.ops.insert_add(normalized_key, Op::Write);
         self.fns.insert_add(normalized_key, Transform::Write(value));
+        self.record(JournalEntry::write(&normalized_key));
     }
 
     /// Ok(None) represents missing key to which we want to "add" some value.
@@ -317,6 +336,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                 self.cache.insert_write(normalized_key, new_value);
                 self.ops.insert_add(normalized_key, Op::Add);
                 self.fns.insert_add(normalized_key, transform);
+                self.record(JournalEntry::add(&normalized_key));
                 Ok(AddResult::Success)
             }
             Err(transform::Error::TypeMismatch(type_mismatch)) => {
@@ -328,6 +348,19 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
 
     pub fn effect(&self) -> ExecutionEffect {
         ExecutionEffect::new(self.ops.clone(), self.fns.clone())
+            .with_journal(self.journal.borrow().clone())
+    }
+
+    /// Returns a handle to the execution journal of this tracking copy, into which operations are
+    /// recorded when the execution journal is enabled.
+    pub fn journal(&self) -> Rc<RefCell<ExecutionJournal>> {
+        Rc::clone(&self.journal)
+    }
+
+    fn record(&self, entry: JournalEntry) {
+        if self.record_journal {
+            self.journal.borrow_mut().push(entry)
+        }
     }
 
     /// Calling `query()` avoids calling into `self.cache`, so this will not return any values
@@ -14,7 +14,7 @@ use super::{
     meter::count_meter::Count, AddResult, TrackingCopy, TrackingCopyCache, TrackingCopyQueryResult,
 };
 use crate::{
-    core::engine_state::op::Op,
+    core::engine_state::{execution_journal::JournalEntry, op::Op},
     shared::{
         account::{Account, AssociatedKeys},
         newtypes::CorrelationId,
@@ -113,6 +113,39 @@ fn tracking_copy_read() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Read));
 }
 
+#[test]
+fn tracking_copy_journal() {
+    let correlation_id = CorrelationId::new();
+    let k = Key::Hash([0u8; 32]);
+    let one = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+
+    // nothing is recorded unless the journal is enabled
+    let mut tc = TrackingCopy::new(CountingDb::new(Rc::new(Cell::new(0))));
+    tc.read(correlation_id, &k).unwrap();
+    tc.write(k, one.clone());
+    assert!(tc.journal().borrow().is_empty());
+
+    let mut tc =
+        TrackingCopy::new(CountingDb::new(Rc::new(Cell::new(0)))).with_execution_journal(true);
+    tc.read(correlation_id, &k).unwrap();
+    tc.write(k, one.clone());
+    assert_matches!(tc.add(correlation_id, k, one), Ok(AddResult::Success));
+    assert_eq!(
+        tc.journal().borrow().entries(),
+        &[
+            JournalEntry::read(&k),
+            JournalEntry::write(&k),
+            JournalEntry::add(&k)
+        ]
+    );
+
+    // forks inherit the setting, but record into their own journal
+    let mut fork = tc.fork();
+    fork.read(correlation_id, &k).unwrap();
+    assert_eq!(fork.journal().borrow().entries(), &[JournalEntry::read(&k)]);
+    assert_eq!(tc.journal().borrow().entries().len(), 3);
+}
+
 #[test]
 fn tracking_copy_write() {
     let counter = Rc::new(Cell::new(0));
@@ -10,7 +10,7 @@ use casper_types::{Key, ProtocolVersion};
 
 use crate::storage::{
     error::{self, in_memory},
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit, CommitResult, StateKeysReader, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::in_memory::InMemoryProtocolDataStore,
     store::Store,
@@ -21,7 +21,7 @@ use crate::storage::{
     trie::{operations::create_hashed_empty_trie, Trie},
     trie_store::{
         in_memory::InMemoryTrieStore,
-        operations::{self, read, ReadResult, WriteResult},
+        operations::{self, keys_with_prefix, read, ReadResult, WriteResult},
     },
 };
 
@@ -139,6 +139,29 @@ impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
     }
 }
 
+impl StateKeysReader<Key, StoredValue> for InMemoryGlobalStateView {
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let keys_iter = keys_with_prefix::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+        );
+        let mut ret = Vec::new();
+        for result in keys_iter {
+            ret.push(result?);
+        }
+        txn.commit()?;
+        Ok(ret)
+    }
+}
+
 impl StateProvider for InMemoryGlobalState {
     type Error = error::Error;
 
@@ -201,7 +224,10 @@ impl StateProvider for InMemoryGlobalState {
 
 #[cfg(test)]
 mod tests {
-    use crate::shared::newtypes::Blake2bHash;
+    use crate::{
+        shared::newtypes::Blake2bHash,
+        storage::global_state::{self, StoredValueDiff},
+    };
     use casper_types::{account::AccountHash, CLValue};
 
     use super::*;
@@ -344,6 +370,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn diff_reports_added_removed_and_modified_keys() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs = create_test_pairs();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = test_pairs_updated
+            .iter()
+            .skip(1)
+            .cloned()
+            .map(|TestPair { key, value }| (key, Transform::Write(value)))
+            .collect();
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let forward = global_state::diff(&state, correlation_id, root_hash, updated_hash)
+            .unwrap()
+            .unwrap();
+        assert_eq!(forward.len(), 2);
+        assert_eq!(
+            forward.get(&test_pairs[1].key),
+            Some(&StoredValueDiff::Modified {
+                before: test_pairs[1].value.clone(),
+                after: test_pairs_updated[1].value.clone(),
+            })
+        );
+        assert_eq!(
+            forward.get(&test_pairs_updated[2].key),
+            Some(&StoredValueDiff::Added(test_pairs_updated[2].value.clone()))
+        );
+
+        let backward = global_state::diff(&state, correlation_id, updated_hash, root_hash)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            backward.get(&test_pairs_updated[2].key),
+            Some(&StoredValueDiff::Removed(
+                test_pairs_updated[2].value.clone()
+            ))
+        );
+
+        let unchanged = global_state::diff(&state, correlation_id, root_hash, root_hash)
+            .unwrap()
+            .unwrap();
+        assert!(unchanged.is_empty());
+
+        let fake_hash = Blake2bHash::new(&[1, 2, 3]);
+        assert!(
+            global_state::diff(&state, correlation_id, root_hash, fake_hash)
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     fn initial_state_has_the_expected_hash() {
         let correlation_id = CorrelationId::new();
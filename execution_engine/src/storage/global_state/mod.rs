@@ -1,7 +1,7 @@
 pub mod in_memory;
 pub mod lmdb;
 
-use std::{fmt, hash::BuildHasher};
+use std::{collections::BTreeMap, fmt, hash::BuildHasher};
 
 use crate::shared::{
     additive_map::AdditiveMap,
@@ -31,6 +31,19 @@ pub trait StateReader<K, V> {
     fn read(&self, correlation_id: CorrelationId, key: &K) -> Result<Option<V>, Self::Error>;
 }
 
+/// A reader of state which can also list the keys it holds.
+///
+/// Only needed by [`diff`], so it is kept apart from [`StateReader`] rather than being required
+/// of every reader.
+pub trait StateKeysReader<K, V>: StateReader<K, V> {
+    /// Returns the keys in the trie matching `prefix`.
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<K>, Self::Error>;
+}
+
 #[derive(Debug)]
 pub enum CommitResult {
     RootNotFound,
@@ -97,6 +110,78 @@ pub trait StateProvider {
     fn empty_root(&self) -> Blake2bHash;
 }
 
+/// The difference in the value stored under a single key between two post states.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StoredValueDiff {
+    /// The key only exists in the later post state.
+    Added(StoredValue),
+    /// The key only exists in the earlier post state.
+    Removed(StoredValue),
+    /// The key exists in both post states, but with different values.
+    Modified {
+        before: StoredValue,
+        after: StoredValue,
+    },
+}
+
+/// Returns the keys whose values differ between the post states `before` and `after`, along with
+/// the values on either side.
+///
+/// Returns `Ok(None)` if either post state hash is not found.
+///
+/// This reads every key in both post states, so it is intended for tests only and should not be
+/// used while executing deploys.
+pub fn diff<P>(
+    state: &P,
+    correlation_id: CorrelationId,
+    before: Blake2bHash,
+    after: Blake2bHash,
+) -> Result<Option<BTreeMap<Key, StoredValueDiff>>, P::Error>
+where
+    P: StateProvider,
+    P::Reader: StateKeysReader<Key, StoredValue>,
+{
+    let (before_reader, after_reader) = match (state.checkout(before)?, state.checkout(after)?) {
+        (Some(before_reader), Some(after_reader)) => (before_reader, after_reader),
+        _ => return Ok(None),
+    };
+
+    let mut before_values = BTreeMap::new();
+    for key in before_reader.keys_with_prefix(correlation_id, &[])? {
+        if let Some(value) = before_reader.read(correlation_id, &key)? {
+            before_values.insert(key, value);
+        }
+    }
+
+    let mut ret = BTreeMap::new();
+    for key in after_reader.keys_with_prefix(correlation_id, &[])? {
+        let after_value = match after_reader.read(correlation_id, &key)? {
+            Some(value) => value,
+            None => continue,
+        };
+        match before_values.remove(&key) {
+            Some(before_value) if before_value == after_value => {}
+            Some(before_value) => {
+                ret.insert(
+                    key,
+                    StoredValueDiff::Modified {
+                        before: before_value,
+                        after: after_value,
+                    },
+                );
+            }
+            None => {
+                ret.insert(key, StoredValueDiff::Added(after_value));
+            }
+        }
+    }
+    for (key, before_value) in before_values {
+        ret.insert(key, StoredValueDiff::Removed(before_value));
+    }
+
+    Ok(Some(ret))
+}
+
 pub fn commit<'a, R, S, H, E>(
     environment: &'a R,
     store: &S,
@@ -1,3 +1,5 @@
+use std::{collections::BTreeSet, fmt::Write};
+
 use casper_execution_engine::shared::{additive_map::AdditiveMap, transform::Transform};
 use casper_types::Key;
 
@@ -48,6 +50,58 @@ impl AdditiveMapDiff {
     pub fn both(&self) -> &AdditiveMap<Key, Transform> {
         &self.both
     }
+
+    /// Asserts that `self` and `other` are equal.
+    ///
+    /// On failure, panics with a per-key breakdown of the entries which were added, removed or
+    /// changed in `other` relative to `self`.
+    pub fn assert_eq(&self, other: &AdditiveMapDiff) {
+        if self == other {
+            return;
+        }
+
+        let mut report = String::new();
+        for (section, expected, actual) in &[
+            ("left", &self.left, &other.left),
+            ("both", &self.both, &other.both),
+            ("right", &self.right, &other.right),
+        ] {
+            describe_differences(section, expected, actual, &mut report);
+        }
+        panic!("AdditiveMapDiffs are not equal:\n{}", report);
+    }
+}
+
+fn describe_differences(
+    section: &str,
+    expected: &AdditiveMap<Key, Transform>,
+    actual: &AdditiveMap<Key, Transform>,
+    report: &mut String,
+) {
+    let keys: BTreeSet<&Key> = expected.keys().chain(actual.keys()).collect();
+    for key in keys {
+        // Writing to a `String` can't fail.
+        let _ = match (expected.get(key), actual.get(key)) {
+            (Some(expected_value), None) => writeln!(
+                report,
+                "  {}: removed {:?} => {:?}",
+                section, key, expected_value
+            ),
+            (None, Some(actual_value)) => writeln!(
+                report,
+                "  {}: added {:?} => {:?}",
+                section, key, actual_value
+            ),
+            (Some(expected_value), Some(actual_value)) if expected_value != actual_value => {
+                writeln!(
+                    report,
+                    "  {}: changed {:?} => {:?} to {:?}",
+                    section, key, expected_value, actual_value
+                )
+            }
+            _ => Ok(()),
+        };
+    }
 }
 
 #[cfg(test)]
@@ -192,4 +246,22 @@ mod tests {
         let fixture = TestFixture::new(AdditiveMap::new(), AdditiveMap::new(), AdditiveMap::new());
         fixture.run();
     }
+
+    #[test]
+    fn should_assert_eq_for_equal_diffs() {
+        let fixture = TestFixture::new(LEFT_ONLY.clone(), BOTH.clone(), RIGHT_ONLY.clone());
+        let diff = AdditiveMapDiff::new(fixture.left(), fixture.right());
+        fixture.expected.assert_eq(&diff);
+    }
+
+    #[test]
+    #[should_panic(expected = "both: changed")]
+    fn should_fail_assert_eq_for_changed_entry() {
+        let fixture = TestFixture::new(AdditiveMap::new(), BOTH.clone(), AdditiveMap::new());
+        let mut both = BOTH.clone();
+        let key = *both.keys().next().unwrap();
+        both.insert(key, Transform::AddInt32(1));
+        let diff = AdditiveMapDiff::new(both.clone(), both);
+        fixture.expected.assert_eq(&diff);
+    }
 }
@@ -8,8 +8,10 @@ use casper_execution_engine::{
     shared::newtypes::Blake2bHash,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::ToBytes, contracts::ContractVersion, ContractHash, HashAddr,
-    RuntimeArgs,
+    account::AccountHash,
+    bytesrepr::{self, ToBytes},
+    contracts::ContractVersion,
+    ContractHash, HashAddr, RuntimeArgs,
 };
 
 use crate::internal::utils;
@@ -22,6 +24,7 @@ struct DeployItemData {
     pub gas_price: u64,
     pub authorization_keys: BTreeSet<AccountHash>,
     pub deploy_hash: DeployHash,
+    pub canonical_args: bool,
 }
 
 pub struct DeployItemBuilder {
@@ -139,11 +142,12 @@ impl DeployItemBuilder {
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
+        let args = Self::serialize_args(args);
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredVersionedContractByName {
             name: name.to_owned(),
             version,
             entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
+            args,
         });
         self
     }
@@ -155,11 +159,12 @@ impl DeployItemBuilder {
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
+        let args = Self::serialize_args(args);
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredVersionedContractByHash {
             hash,
             version,
             entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
+            args,
         });
         self
     }
@@ -171,11 +176,12 @@ impl DeployItemBuilder {
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
+        let args = Self::serialize_args(args);
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredVersionedContractByName {
             name: key_name.to_owned(),
             version,
             entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
+            args,
         });
         self
     }
@@ -187,11 +193,12 @@ impl DeployItemBuilder {
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
+        let args = Self::serialize_args(args);
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredVersionedContractByHash {
             hash,
             version,
             entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
+            args,
         });
         self
     }
@@ -219,20 +226,35 @@ impl DeployItemBuilder {
         self
     }
 
+    /// Sorts the arguments of the payment and session code by name when the deploy is built, so
+    /// that it serializes identically regardless of argument insertion order.
+    ///
+    /// `build` panics if any of the arguments share a name.
+    pub fn with_canonical_args(mut self) -> Self {
+        self.deploy_item.canonical_args = true;
+        self
+    }
+
     pub fn build(self) -> DeployItem {
+        let mut session = self
+            .deploy_item
+            .session_code
+            .expect("should have session code");
+        let mut payment = self
+            .deploy_item
+            .payment_code
+            .expect("should have payment code");
+        if self.deploy_item.canonical_args {
+            session = Self::canonicalize_args(session);
+            payment = Self::canonicalize_args(payment);
+        }
         DeployItem {
             address: self
                 .deploy_item
                 .address
                 .unwrap_or_else(|| AccountHash::new([0u8; 32])),
-            session: self
-                .deploy_item
-                .session_code
-                .expect("should have session code"),
-            payment: self
-                .deploy_item
-                .payment_code
-                .expect("should have payment code"),
+            session,
+            payment,
             gas_price: self.deploy_item.gas_price,
             authorization_keys: self.deploy_item.authorization_keys,
             deploy_hash: self.deploy_item.deploy_hash,
@@ -242,6 +264,25 @@ impl DeployItemBuilder {
     fn serialize_args(args: RuntimeArgs) -> Vec<u8> {
         args.into_bytes().expect("should serialize args")
     }
+
+    fn canonicalize_args(mut item: ExecutableDeployItem) -> ExecutableDeployItem {
+        match &mut item {
+            ExecutableDeployItem::ModuleBytes { args, .. }
+            | ExecutableDeployItem::StoredContractByHash { args, .. }
+            | ExecutableDeployItem::StoredContractByName { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
+            | ExecutableDeployItem::Transfer { args } => {
+                let runtime_args: RuntimeArgs =
+                    bytesrepr::deserialize(args.clone()).expect("should deserialize args");
+                let runtime_args = runtime_args
+                    .into_canonical()
+                    .expect("should not have duplicated argument names");
+                *args = Self::serialize_args(runtime_args);
+            }
+        }
+        item
+    }
 }
 
 impl Default for DeployItemBuilder {
@@ -144,7 +144,7 @@ where
         context,
     );
 
-    match instance.invoke_export(entry_point_name, &[], &mut runtime) {
+    match runtime::invoke_export(&instance, entry_point_name, &mut runtime) {
         Ok(_) => None,
         Err(e) => {
             if let Some(host_error) = e.as_host_error() {
@@ -2,12 +2,16 @@ use std::convert::TryInto;
 
 use rand::Rng;
 
-use casper_execution_engine::core::engine_state::{
-    deploy_item::DeployItem, execute_request::ExecuteRequest,
+use casper_execution_engine::{
+    core::engine_state::{
+        deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem,
+        execute_request::ExecuteRequest,
+    },
+    shared::gas::Gas,
 };
 use casper_types::{
-    account::AccountHash, contracts::ContractVersion, runtime_args, ContractHash, ProtocolVersion,
-    RuntimeArgs,
+    account::AccountHash, bytesrepr::ToBytes, contracts::ContractVersion, runtime_args,
+    ContractHash, ProtocolVersion, RuntimeArgs,
 };
 
 use crate::internal::{DeployItemBuilder, DEFAULT_BLOCK_TIME, DEFAULT_PAYMENT};
@@ -48,6 +52,51 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    /// Caps the gas limit of the session code of every deploy at `gas_limit`, regardless of the
+    /// payment made for it.
+    pub fn with_gas_limit(mut self, gas_limit: Gas) -> Self {
+        self.execute_request.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the deploy hash of the most recently pushed deploy.
+    ///
+    /// Addresses of URefs, contracts and contract packages created by a deploy are derived from
+    /// its deploy hash, which is otherwise random.  Pinning it makes them identical across runs.
+    pub fn with_deploy_hash(mut self, deploy_hash: [u8; 32]) -> Self {
+        self.last_deploy_mut().deploy_hash = deploy_hash;
+        self
+    }
+
+    /// Replaces the authorization keys of the most recently pushed deploy, e.g. to sign it with
+    /// several of an account's associated keys.
+    pub fn with_authorization_keys(mut self, authorization_keys: &[AccountHash]) -> Self {
+        self.last_deploy_mut().authorization_keys = authorization_keys.iter().copied().collect();
+        self
+    }
+
+    /// Replaces the session code of the most recently pushed deploy with the given module bytes.
+    pub fn with_session_bytes(mut self, module_bytes: Vec<u8>, args: RuntimeArgs) -> Self {
+        let args = args.into_bytes().expect("should serialize args");
+        self.last_deploy_mut().session = ExecutableDeployItem::ModuleBytes { module_bytes, args };
+        self
+    }
+
+    /// Replaces the payment code of the most recently pushed deploy with the given module bytes.
+    pub fn with_payment_bytes(mut self, module_bytes: Vec<u8>, args: RuntimeArgs) -> Self {
+        let args = args.into_bytes().expect("should serialize args");
+        self.last_deploy_mut().payment = ExecutableDeployItem::ModuleBytes { module_bytes, args };
+        self
+    }
+
+    fn last_deploy_mut(&mut self) -> &mut DeployItem {
+        self.execute_request
+            .deploys
+            .last_mut()
+            .and_then(|deploy| deploy.as_mut().ok())
+            .expect("should have a deploy")
+    }
+
     pub fn build(self) -> ExecuteRequest {
         self.execute_request
     }
@@ -73,6 +122,29 @@ impl ExecuteRequestBuilder {
         ExecuteRequestBuilder::new().push_deploy(deploy)
     }
 
+    /// Like [`ExecuteRequestBuilder::standard`], but with session code given as module bytes
+    /// rather than read from a wasm file.
+    pub fn module_bytes(
+        account_hash: AccountHash,
+        module_bytes: Vec<u8>,
+        session_args: RuntimeArgs,
+    ) -> Self {
+        let mut rng = rand::thread_rng();
+        let deploy_hash: [u8; 32] = rng.gen();
+
+        let deploy = DeployItemBuilder::new()
+            .with_address(account_hash)
+            .with_session_bytes(module_bytes, session_args)
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_authorization_keys(&[account_hash])
+            .with_deploy_hash(deploy_hash)
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy)
+    }
+
     pub fn contract_call_by_hash(
         sender: AccountHash,
         contract_hash: ContractHash,
@@ -39,6 +39,15 @@ pub const MOCKED_ACCOUNT_ADDRESS: AccountHash = AccountHash::new([48u8; 32]);
 
 pub const ARG_AMOUNT: &str = "amount";
 
+/// Secret key of the secp256k1 default account.
+pub const DEFAULT_SECP256K1_ACCOUNT_SECRET_KEY: [u8; 32] = [42; 32];
+/// Compressed SEC1 encoding of the public key for [`DEFAULT_SECP256K1_ACCOUNT_SECRET_KEY`].
+const DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY_BYTES: [u8; 33] = [
+    3, 91, 229, 233, 71, 130, 9, 103, 74, 150, 230, 15, 31, 3, 127, 97, 118, 84, 15, 208, 1, 250,
+    29, 100, 105, 71, 112, 197, 106, 119, 9, 196, 44,
+];
+const SECP256K1_ALGORITHM_NAME: &str = "secp256k1";
+
 lazy_static! {
     // NOTE: Those values could be contants but are kept az lazy statics to avoid changes of `*FOO` into `FOO` back and forth.
     pub static ref DEFAULT_GENESIS_CONFIG_HASH: Blake2bHash = [42; 32].into();
@@ -64,6 +73,25 @@ lazy_static! {
         ret.push(genesis_account);
         ret
     };
+    pub static ref DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY: PublicKey =
+        PublicKey::Secp256k1(DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY_BYTES.into());
+    pub static ref DEFAULT_SECP256K1_ACCOUNT_ADDR: AccountHash = {
+        // Derived the same way as the node derives account hashes: the blake2b hash of the
+        // algorithm name, a zero byte and the public key bytes.
+        let mut preimage = SECP256K1_ALGORITHM_NAME.as_bytes().to_vec();
+        preimage.push(0);
+        preimage.extend_from_slice(&DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY_BYTES);
+        AccountHash::new(Blake2bHash::new(&preimage).value())
+    };
+    pub static ref DEFAULT_SECP256K1_ACCOUNT_KEY: AccountHash = *DEFAULT_SECP256K1_ACCOUNT_ADDR;
+    /// Genesis entry for the secp256k1 default account. It is not part of [`DEFAULT_ACCOUNTS`], so
+    /// tests using it need to add it to their genesis accounts.
+    pub static ref DEFAULT_SECP256K1_GENESIS_ACCOUNT: GenesisAccount = GenesisAccount::new(
+        *DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY,
+        *DEFAULT_SECP256K1_ACCOUNT_ADDR,
+        Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+        Motes::zero(),
+    );
     pub static ref DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
     pub static ref DEFAULT_PAYMENT: U512 = 100_000_000.into();
     pub static ref DEFAULT_WASM_COSTS: WasmCosts = test_utils::wasm_costs_mock();
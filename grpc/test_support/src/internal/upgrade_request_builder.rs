@@ -6,13 +6,14 @@ use casper_engine_grpc_server::engine_server::{
     state,
 };
 use casper_execution_engine::shared::wasm_costs::WasmCosts;
-use casper_types::ProtocolVersion;
+use casper_types::{bytesrepr::ToBytes, ProtocolVersion, RuntimeArgs};
 
 pub struct UpgradeRequestBuilder {
     pre_state_hash: Vec<u8>,
     current_protocol_version: state::ProtocolVersion,
     new_protocol_version: state::ProtocolVersion,
     upgrade_installer: DeployCode,
+    upgrade_installer_args: Option<RuntimeArgs>,
     new_costs: Option<ChainSpec_CostTable_WasmCosts>,
     activation_point: ChainSpec_ActivationPoint,
 }
@@ -42,6 +43,13 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    /// Sets the arguments passed to the upgrade installer, replacing any already set on the
+    /// installer code.
+    pub fn with_installer_args(mut self, args: RuntimeArgs) -> Self {
+        self.upgrade_installer_args = Some(args);
+        self
+    }
+
     pub fn with_new_costs(mut self, wasm_costs: WasmCosts) -> Self {
         let mut new_costs = ChainSpec_CostTable_WasmCosts::new();
         new_costs.set_regular(wasm_costs.regular);
@@ -67,7 +75,12 @@ impl UpgradeRequestBuilder {
         self
     }
 
-    pub fn build(self) -> UpgradeRequest {
+    pub fn build(mut self) -> UpgradeRequest {
+        if let Some(args) = self.upgrade_installer_args {
+            let args = args.to_bytes().expect("should serialize installer args");
+            self.upgrade_installer.set_args(args);
+        }
+
         let mut upgrade_point = ChainSpec_UpgradePoint::new();
         upgrade_point.set_activation_point(self.activation_point);
         match self.new_costs {
@@ -95,6 +108,7 @@ impl Default for UpgradeRequestBuilder {
             current_protocol_version: Default::default(),
             new_protocol_version: Default::default(),
             upgrade_installer: Default::default(),
+            upgrade_installer_args: None,
             new_costs: None,
             activation_point: Default::default(),
         }
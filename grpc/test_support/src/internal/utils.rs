@@ -15,7 +15,7 @@ use casper_execution_engine::{
     },
     shared::{
         account::Account, additive_map::AdditiveMap, gas::Gas, stored_value::StoredValue,
-        transform::Transform,
+        transform::Transform, wasm_costs::WasmCosts,
     },
 };
 use casper_types::Key;
@@ -123,11 +123,21 @@ pub fn read_wasm_file_bytes<T: AsRef<Path>>(contract_file: T) -> Vec<u8> {
 }
 
 pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
+    create_exec_config_with_wasm_costs(accounts, *DEFAULT_WASM_COSTS)
+}
+
+/// Creates an `ExecConfig` which uses the given `wasm_costs` in place of `DEFAULT_WASM_COSTS`.
+///
+/// The costs are stored in the protocol data at genesis, so they apply to every deploy executed
+/// afterwards, until replaced by an upgrade (see `UpgradeRequestBuilder::with_new_costs`).
+pub fn create_exec_config_with_wasm_costs(
+    accounts: Vec<GenesisAccount>,
+    wasm_costs: WasmCosts,
+) -> ExecConfig {
     let mint_installer_bytes = read_wasm_file_bytes(MINT_INSTALL_CONTRACT);
     let proof_of_stake_installer_bytes = read_wasm_file_bytes(POS_INSTALL_CONTRACT);
     let standard_payment_installer_bytes = read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT);
     let auction_installer_bytes = read_wasm_file_bytes(AUCTION_INSTALL_CONTRACT);
-    let wasm_costs = *DEFAULT_WASM_COSTS;
     ExecConfig::new(
         mint_installer_bytes,
         proof_of_stake_installer_bytes,
@@ -148,7 +158,16 @@ pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
 }
 
 pub fn create_run_genesis_request(accounts: Vec<GenesisAccount>) -> RunGenesisRequest {
-    let exec_config = create_exec_config(accounts);
+    create_run_genesis_request_with_wasm_costs(accounts, *DEFAULT_WASM_COSTS)
+}
+
+/// Creates a `RunGenesisRequest` which uses the given `wasm_costs` in place of
+/// `DEFAULT_WASM_COSTS`.  See [`create_exec_config_with_wasm_costs`].
+pub fn create_run_genesis_request_with_wasm_costs(
+    accounts: Vec<GenesisAccount>,
+    wasm_costs: WasmCosts,
+) -> RunGenesisRequest {
+    let exec_config = create_exec_config_with_wasm_costs(accounts, wasm_costs);
     RunGenesisRequest::new(
         *DEFAULT_GENESIS_CONFIG_HASH,
         *DEFAULT_PROTOCOL_VERSION,
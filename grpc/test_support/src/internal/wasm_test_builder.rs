@@ -1,4 +1,5 @@
 use std::{
+    collections::{BTreeMap, BTreeSet},
     convert::{TryFrom, TryInto},
     ffi::OsStr,
     fs,
@@ -20,11 +21,14 @@ use casper_engine_grpc_server::engine_server::{
     mappings::TransformMap,
     transforms::TransformEntry,
 };
+#[cfg(feature = "test-support")]
+use casper_execution_engine::core::runtime;
 use casper_execution_engine::{
     core::{
         engine_state::{
             execute_request::ExecuteRequest, execution_result::ExecutionResult,
-            run_genesis_request::RunGenesisRequest, EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
+            run_genesis_request::RunGenesisRequest, EngineConfig, EngineState, Error,
+            SYSTEM_ACCOUNT_ADDR,
         },
         execution,
     },
@@ -39,7 +43,10 @@ use casper_execution_engine::{
         utils::OS_PAGE_SIZE,
     },
     storage::{
-        global_state::{in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, StateProvider},
+        global_state::{
+            in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, StateKeysReader, StateProvider,
+            StoredValueDiff,
+        },
         protocol_data_store::lmdb::LmdbProtocolDataStore,
         transaction_source::lmdb::LmdbEnvironment,
         trie_store::lmdb::LmdbTrieStore,
@@ -47,11 +54,18 @@ use casper_execution_engine::{
 };
 use casper_types::{
     account::AccountHash,
-    bytesrepr::{self},
-    CLValue, Contract, ContractHash, ContractWasm, Key, URef, U512,
+    auction::{
+        Bids, Delegators, UnbondingPurse, UnbondingPurses, BIDS_KEY, DELEGATORS_KEY,
+        UNBONDING_PURSES_KEY,
+    },
+    bytesrepr::{self, FromBytes},
+    contracts::NamedKeys,
+    system_contract_errors::mint,
+    ApiError, BlockTime, CLType, CLTyped, CLValue, Contract, ContractHash, ContractPackage,
+    ContractPackageHash, ContractVersionKey, ContractWasm, Key, PublicKey, URef, U512,
 };
 
-use crate::internal::utils;
+use crate::internal::{utils, DEFAULT_BLOCK_TIME, DEFAULT_PROTOCOL_VERSION};
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
 ///
@@ -79,6 +93,8 @@ pub struct WasmTestBuilder<S> {
     transforms: Vec<AdditiveMap<Key, Transform>>,
     /// Cached genesis transforms
     genesis_account: Option<Account>,
+    /// Account hashes of the genesis accounts which have a public key
+    genesis_account_hashes: BTreeMap<PublicKey, AccountHash>,
     /// Genesis transforms
     genesis_transforms: Option<AdditiveMap<Key, Transform>>,
     /// Mint contract key
@@ -89,6 +105,8 @@ pub struct WasmTestBuilder<S> {
     standard_payment_hash: Option<ContractHash>,
     /// Auction contract key
     auction_contract_hash: Option<ContractHash>,
+    /// Text printed by contracts during `exec` calls
+    captured_output: Vec<String>,
 }
 
 impl<S> WasmTestBuilder<S> {
@@ -100,27 +118,9 @@ impl<S> WasmTestBuilder<S> {
 
 impl Default for InMemoryWasmTestBuilder {
     fn default() -> Self {
-        Self::initialize_logging();
         let engine_config =
             EngineConfig::new().with_use_system_contracts(cfg!(feature = "use-system-contracts"));
-
-        let global_state = InMemoryGlobalState::empty().expect("should create global state");
-        let engine_state = EngineState::new(global_state, engine_config);
-
-        WasmTestBuilder {
-            engine_state: Rc::new(engine_state),
-            exec_responses: Vec::new(),
-            upgrade_responses: Vec::new(),
-            genesis_hash: None,
-            post_state_hash: None,
-            transforms: Vec::new(),
-            genesis_account: None,
-            genesis_transforms: None,
-            mint_contract_hash: None,
-            pos_contract_hash: None,
-            standard_payment_hash: None,
-            auction_contract_hash: None,
-        }
+        Self::new_with_config(engine_config)
     }
 }
 
@@ -136,11 +136,13 @@ impl<S> Clone for WasmTestBuilder<S> {
             post_state_hash: self.post_state_hash.clone(),
             transforms: self.transforms.clone(),
             genesis_account: self.genesis_account.clone(),
+            genesis_account_hashes: self.genesis_account_hashes.clone(),
             genesis_transforms: self.genesis_transforms.clone(),
             mint_contract_hash: self.mint_contract_hash,
             pos_contract_hash: self.pos_contract_hash,
             standard_payment_hash: self.standard_payment_hash,
             auction_contract_hash: self.auction_contract_hash,
+            captured_output: self.captured_output.clone(),
         }
     }
 }
@@ -171,6 +173,30 @@ impl InMemoryWasmTestBuilder {
             ..Default::default()
         }
     }
+
+    /// Creates a builder backed by empty in-memory global state using the given `engine_config`.
+    pub fn new_with_config(engine_config: EngineConfig) -> Self {
+        Self::initialize_logging();
+        let global_state = InMemoryGlobalState::empty().expect("should create global state");
+        let engine_state = EngineState::new(global_state, engine_config);
+
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            exec_responses: Vec::new(),
+            upgrade_responses: Vec::new(),
+            genesis_hash: None,
+            post_state_hash: None,
+            transforms: Vec::new(),
+            genesis_account: None,
+            genesis_account_hashes: BTreeMap::new(),
+            genesis_transforms: None,
+            mint_contract_hash: None,
+            pos_contract_hash: None,
+            standard_payment_hash: None,
+            auction_contract_hash: None,
+            captured_output: Vec::new(),
+        }
+    }
 }
 
 impl LmdbWasmTestBuilder {
@@ -204,11 +230,13 @@ impl LmdbWasmTestBuilder {
             post_state_hash: None,
             transforms: Vec::new(),
             genesis_account: None,
+            genesis_account_hashes: BTreeMap::new(),
             genesis_transforms: None,
             mint_contract_hash: None,
             pos_contract_hash: None,
             standard_payment_hash: None,
             auction_contract_hash: None,
+            captured_output: Vec::new(),
         }
     }
 
@@ -264,14 +292,38 @@ impl LmdbWasmTestBuilder {
             post_state_hash: Some(post_state_hash),
             transforms: Vec::new(),
             genesis_account: None,
+            genesis_account_hashes: BTreeMap::new(),
             genesis_transforms: None,
             mint_contract_hash: None,
             pos_contract_hash: None,
             standard_payment_hash: None,
             auction_contract_hash: None,
+            captured_output: Vec::new(),
         }
     }
 
+    /// Returns the current post-state hash, which can be passed to [`restore_checkpoint`] to run
+    /// further deploys from the current state.
+    ///
+    /// [`restore_checkpoint`]: LmdbWasmTestBuilder::restore_checkpoint
+    pub fn save_checkpoint(&self) -> Blake2bHash {
+        Blake2bHash::try_from(self.get_post_state_hash().as_slice())
+            .expect("post-state hash should be a valid Blake2b hash")
+    }
+
+    /// Resets the post-state hash to a checkpoint returned by [`save_checkpoint`], so that
+    /// subsequent deploys execute against the state at that checkpoint.
+    ///
+    /// Global state is never deleted, so anything written after the checkpoint remains in the
+    /// store but is unreachable from the restored root.  Cached exec responses and transforms are
+    /// left as they are.
+    ///
+    /// [`save_checkpoint`]: LmdbWasmTestBuilder::save_checkpoint
+    pub fn restore_checkpoint(&mut self, checkpoint: Blake2bHash) -> &mut Self {
+        self.post_state_hash = Some(checkpoint.to_vec());
+        self
+    }
+
     fn create_and_get_global_state_dir<T: AsRef<OsStr> + ?Sized>(data_dir: &T) -> PathBuf {
         let global_state_path = {
             let mut path = PathBuf::from(data_dir);
@@ -300,6 +352,7 @@ where
             post_state_hash: result.0.post_state_hash,
             transforms: Vec::new(),
             genesis_account: result.0.genesis_account,
+            genesis_account_hashes: result.0.genesis_account_hashes,
             mint_contract_hash: result.0.mint_contract_hash,
             pos_contract_hash: result.0.pos_contract_hash,
             standard_payment_hash: result.0.standard_payment_hash,
@@ -338,6 +391,17 @@ where
         let genesis_account =
             utils::get_account(&transforms, &system_account).expect("Unable to get system account");
 
+        let genesis_account_hashes = run_genesis_request
+            .ee_config()
+            .accounts()
+            .iter()
+            .filter_map(|account| {
+                account
+                    .public_key()
+                    .map(|public_key| (public_key, account.account_hash()))
+            })
+            .collect();
+
         let maybe_protocol_data = self
             .engine_state
             .get_protocol_data(run_genesis_request.protocol_version())
@@ -351,10 +415,87 @@ where
         self.standard_payment_hash = Some(protocol_data.standard_payment());
         self.auction_contract_hash = Some(protocol_data.auction());
         self.genesis_account = Some(genesis_account);
+        self.genesis_account_hashes = genesis_account_hashes;
         self.genesis_transforms = Some(transforms);
         self
     }
 
+    /// Queries `base_key` and `path` at the given `state_hash`, which may be any root previously
+    /// produced by this builder, rather than at the current post-state hash.
+    pub fn query_at_state_hash(
+        &self,
+        state_hash: Blake2bHash,
+        base_key: Key,
+        path: &[&str],
+    ) -> Result<StoredValue, String> {
+        self.query(Some(state_hash.to_vec()), base_key, path)
+    }
+
+    /// Returns the keys whose stored values differ between the post states `before` and `after`,
+    /// which may be any roots previously produced by this builder.
+    pub fn diff_state(
+        &self,
+        before: Blake2bHash,
+        after: Blake2bHash,
+    ) -> BTreeMap<Key, StoredValueDiff>
+    where
+        S::Reader: StateKeysReader<Key, StoredValue>,
+    {
+        self.engine_state
+            .diff_state(CorrelationId::new(), before, after)
+            .expect("should diff state")
+            .expect("should have both post states")
+    }
+
+    /// Asserts that nothing stored by the system contracts differs between the post state `before`
+    /// and the current one.
+    ///
+    /// This covers each system contract, its package and wasm, and the values under its named
+    /// keys.
+    pub fn assert_system_contracts_unchanged(&self, before: Blake2bHash)
+    where
+        S::Reader: StateKeysReader<Key, StoredValue>,
+    {
+        let after: Blake2bHash = self
+            .get_post_state_hash()
+            .as_slice()
+            .try_into()
+            .expect("should have valid post-state hash");
+
+        let system_contract_hashes = [
+            self.get_mint_contract_hash(),
+            self.get_pos_contract_hash(),
+            self.get_standard_payment_contract_hash(),
+            self.get_auction_contract_hash(),
+        ];
+
+        let mut system_keys = BTreeSet::new();
+        for contract_hash in system_contract_hashes.iter() {
+            system_keys.insert(Key::Hash(*contract_hash));
+            for state_hash in &[before, after] {
+                if let Ok(StoredValue::Contract(contract)) =
+                    self.query(Some(state_hash.to_vec()), Key::Hash(*contract_hash), &[])
+                {
+                    system_keys.insert(Key::Hash(contract.contract_package_hash()));
+                    system_keys.insert(Key::Hash(contract.contract_wasm_hash()));
+                    system_keys.extend(contract.named_keys().values().map(|key| key.normalize()));
+                }
+            }
+        }
+
+        let changes: Vec<(Key, StoredValueDiff)> = self
+            .diff_state(before, after)
+            .into_iter()
+            .filter(|(key, _)| system_keys.contains(key))
+            .collect();
+
+        assert!(
+            changes.is_empty(),
+            "system contracts should be unchanged but found {:?}",
+            changes
+        );
+    }
+
     pub fn query(
         &self,
         maybe_post_state: Option<Vec<u8>>,
@@ -391,9 +532,14 @@ where
                 hash.as_slice().try_into().expect("expected a valid hash");
             exec_request
         };
+        // Discard anything printed on this thread outside of this builder's `exec` calls.
+        #[cfg(feature = "test-support")]
+        runtime::take_captured_output();
         let exec_response = self
             .engine_state
             .run_execute(CorrelationId::new(), exec_request);
+        #[cfg(feature = "test-support")]
+        self.captured_output.extend(runtime::take_captured_output());
         assert!(exec_response.is_ok());
         // Parse deploy results
         let execution_results = exec_response.as_ref().unwrap();
@@ -408,6 +554,93 @@ where
         self
     }
 
+    /// Returns the text printed by contracts through `runtime::print` during all `exec` calls so
+    /// far, in order.
+    #[cfg(feature = "test-support")]
+    pub fn captured_output(&self) -> Vec<String> {
+        self.captured_output.clone()
+    }
+
+    /// Executes and commits each of `exec_requests` in order, stopping after the first one whose
+    /// first deploy fails.
+    ///
+    /// On success returns the result of the first deploy of every request.  Otherwise returns the
+    /// index of the failing request along with its result, which has also been committed.
+    pub fn exec_all(
+        &mut self,
+        exec_requests: Vec<ExecuteRequest>,
+    ) -> Result<Vec<Rc<ExecutionResult>>, (usize, Rc<ExecutionResult>)> {
+        let mut results = Vec::with_capacity(exec_requests.len());
+        for (index, exec_request) in exec_requests.into_iter().enumerate() {
+            self.exec(exec_request).commit();
+            let exec_result = self
+                .exec_responses
+                .last()
+                .and_then(|exec_response| exec_response.get(0))
+                .cloned()
+                .expect("Unable to get first deploy result");
+            if exec_result.is_failure() {
+                return Err((index, exec_result));
+            }
+            results.push(exec_result);
+        }
+        Ok(results)
+    }
+
+    /// Transfers `amount` from `source` to `target` by calling the mint directly as the system
+    /// account, and commits the effects.
+    ///
+    /// Returns the mint's own result, so tests can assert on specific mint errors without going
+    /// through session code.
+    pub fn mint_transfer(
+        &mut self,
+        source: URef,
+        target: URef,
+        amount: U512,
+    ) -> Result<(), mint::Error> {
+        let prestate_hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+
+        let (maybe_result, exec_result) = self
+            .engine_state
+            .mint_transfer(
+                CorrelationId::new(),
+                *DEFAULT_PROTOCOL_VERSION,
+                prestate_hash
+                    .as_slice()
+                    .try_into()
+                    .expect("expected a valid hash"),
+                BlockTime::new(DEFAULT_BLOCK_TIME),
+                [0; 32],
+                source,
+                target,
+                amount,
+            )
+            .expect("should have post state");
+
+        let result = match (maybe_result, exec_result.as_error()) {
+            (Some(result), None) => result,
+            (None, Some(Error::Exec(execution::Error::Revert(ApiError::Mint(error_code))))) => {
+                Err(mint::Error::try_from(*error_code)
+                    .unwrap_or_else(|_| panic!("unknown mint error code {}", error_code)))
+            }
+            (_, error) => panic!("mint transfer failed unexpectedly: {:?}", error),
+        };
+
+        self.commit_effects(prestate_hash, exec_result.effect().transforms.clone());
+
+        result
+    }
+
+    /// Drops all cached wasm modules, including system contracts, so that benchmarks can measure
+    /// executions which have to parse them again rather than ones hitting a warm cache.
+    pub fn clear_wasm_caches(&mut self) -> &mut Self {
+        self.engine_state.clear_system_contract_cache();
+        self
+    }
+
     /// Commit effects of previous exec call on the latest post-state hash.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
@@ -455,6 +688,28 @@ where
         self
     }
 
+    /// Overwrites the value stored under `key` with a `CLValue` holding the raw `bytes`, and
+    /// commits the change.
+    ///
+    /// The `CLType` of an existing `CLValue` under `key` is preserved, otherwise `CLType::Any` is
+    /// used.
+    ///
+    /// This is a destructive operation intended for testing only; it allows asserting that reading
+    /// malformed data from global state fails cleanly rather than panicking.
+    pub fn corrupt_key(&mut self, key: Key, bytes: Vec<u8>) -> &mut Self {
+        let cl_type = match self.query(None, key, &[]) {
+            Ok(StoredValue::CLValue(cl_value)) => cl_value.cl_type().clone(),
+            _ => CLType::Any,
+        };
+        let corrupted_value = StoredValue::CLValue(CLValue::from_components(cl_type, bytes));
+
+        let mut effects = AdditiveMap::new();
+        effects.insert(key, Transform::Write(corrupted_value));
+
+        let prestate_hash = self.get_post_state_hash();
+        self.commit_effects(prestate_hash, effects)
+    }
+
     pub fn upgrade_with_upgrade_request(
         &mut self,
         upgrade_request: &mut UpgradeRequest,
@@ -511,6 +766,35 @@ where
         exec_result.is_failure()
     }
 
+    /// Returns the `ApiError` the last deploy reverted with, or `None` if it succeeded or failed
+    /// for any other reason.
+    pub fn last_exec_api_error(&self) -> Option<ApiError> {
+        let exec_response = self
+            .exec_responses
+            .last()
+            .expect("Expected to be called after run()");
+        let exec_result = exec_response
+            .get(0)
+            .expect("Unable to get first execution result");
+        match exec_result.as_error()? {
+            Error::Exec(execution::Error::Revert(api_error)) => Some(*api_error),
+            _ => None,
+        }
+    }
+
+    /// Expects the last deploy to have reverted with exactly `expected`.
+    pub fn expect_revert(&mut self, expected: ApiError) -> &mut Self {
+        let actual = self.last_exec_api_error();
+        if actual != Some(expected) {
+            panic!(
+                "Expected execution to revert with {:?}, but instead got: {:?}",
+                expected,
+                self.exec_responses.last(),
+            );
+        }
+        self
+    }
+
     /// Gets the transform map that's cached between runs
     pub fn get_transforms(&self) -> Vec<AdditiveMap<Key, Transform>> {
         self.transforms.clone()
@@ -556,6 +840,45 @@ where
             .expect("Genesis hash should be present. Should be called after run_genesis.")
     }
 
+    /// Returns the total supply of motes right after genesis.
+    ///
+    /// The mint doesn't track its total supply directly, so this sums the balances of all purses
+    /// held by the mint as of the genesis post-state hash.
+    pub fn initial_total_supply(&self) -> U512 {
+        self.total_supply_at(self.get_genesis_hash())
+    }
+
+    /// Returns the total supply of motes at the current post-state hash.
+    ///
+    /// Computed in the same way as `initial_total_supply`.
+    pub fn get_total_supply(&self) -> U512 {
+        self.total_supply_at(self.get_post_state_hash())
+    }
+
+    fn total_supply_at(&self, state_hash: Vec<u8>) -> U512 {
+        let mint_contract = match self.query(
+            Some(state_hash.clone()),
+            self.get_mint_contract_hash().into(),
+            &[],
+        ) {
+            Ok(StoredValue::Contract(contract)) => contract,
+            _ => panic!("should have mint contract"),
+        };
+
+        mint_contract
+            .named_keys()
+            .values()
+            .filter_map(
+                |key| match self.query(Some(state_hash.clone()), *key, &[]) {
+                    Ok(StoredValue::CLValue(cl_value)) if *cl_value.cl_type() == CLType::U512 => {
+                        cl_value.into_t::<U512>().ok()
+                    }
+                    _ => None,
+                },
+            )
+            .fold(U512::zero(), |total, balance| total + balance)
+    }
+
     pub fn get_post_state_hash(&self) -> Vec<u8> {
         self.post_state_hash
             .clone()
@@ -597,16 +920,26 @@ where
     }
 
     pub fn get_purse_balance(&self, purse: URef) -> U512 {
+        self.get_purse_balance_at_state_hash(None, purse)
+    }
+
+    /// Returns the balance of `purse` at the given `state_hash`, which may be any root previously
+    /// produced by this builder, rather than at the current post-state hash.
+    pub fn get_purse_balance_at(&self, state_hash: Blake2bHash, purse: URef) -> U512 {
+        self.get_purse_balance_at_state_hash(Some(state_hash.to_vec()), purse)
+    }
+
+    fn get_purse_balance_at_state_hash(&self, state_hash: Option<Vec<u8>>, purse: URef) -> U512 {
         let purse_addr = purse.addr();
         let balance_mapping_key = Key::Hash(purse_addr);
 
         let base_key = self
-            .query(None, balance_mapping_key, &[])
+            .query(state_hash.clone(), balance_mapping_key, &[])
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
             .expect("should find balance uref");
 
-        self.query(None, base_key, &[])
+        self.query(state_hash, base_key, &[])
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
             .expect("should parse balance into a U512")
@@ -622,6 +955,60 @@ where
         }
     }
 
+    /// Returns the named keys of the account or contract stored under `key`.
+    ///
+    /// Panics if `key` holds anything other than an account or a contract.
+    pub fn get_named_keys(&self, key: Key) -> NamedKeys {
+        match self.query(None, key, &[]) {
+            Ok(StoredValue::Account(account)) => account.named_keys().clone(),
+            Ok(StoredValue::Contract(contract)) => contract.named_keys().clone(),
+            Ok(other) => panic!(
+                "expected an account or a contract under {:?} but found {:?}",
+                key, other
+            ),
+            Err(error) => panic!("should query {:?}: {}", key, error),
+        }
+    }
+
+    /// Returns the balance of the main purse of the genesis account with the given `public_key`.
+    ///
+    /// Panics if there is no such genesis account, or if the account no longer exists.
+    pub fn get_public_key_balance(&self, public_key: &PublicKey) -> U512 {
+        let account_hash = *self
+            .genesis_account_hashes
+            .get(public_key)
+            .unwrap_or_else(|| panic!("no genesis account with public key {:?}", public_key));
+        let account = self
+            .get_account(account_hash)
+            .unwrap_or_else(|| panic!("account {:?} should exist", account_hash));
+        self.get_purse_balance(account.main_purse())
+    }
+
+    /// Returns the main purse of the given account followed by all URefs stored under its named
+    /// keys, each with the access rights held by the account.
+    pub fn get_account_urefs(&self, account_hash: AccountHash) -> Vec<URef> {
+        let account = self.get_account(account_hash).expect("should have account");
+        let mut urefs = vec![account.main_purse()];
+        urefs.extend(
+            account
+                .named_keys()
+                .values()
+                .filter_map(|key| key.into_uref()),
+        );
+        urefs
+    }
+
+    /// Returns the raw bytes stored under `uref`, or `None` if nothing is stored there or the
+    /// stored value is not a `Vec<u8>`.
+    pub fn get_stored_bytes(&self, uref: URef) -> Option<Vec<u8>> {
+        match self.query(None, Key::URef(uref).normalize(), &[]) {
+            Ok(StoredValue::CLValue(cl_value)) if *cl_value.cl_type() == Vec::<u8>::cl_type() => {
+                cl_value.into_t().ok()
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_contract(&self, contract_hash: ContractHash) -> Option<Contract> {
         let contract_value: StoredValue = self
             .query(None, contract_hash.into(), &[])
@@ -634,6 +1021,33 @@ where
         }
     }
 
+    /// Returns the key of the newest enabled version of the contract package stored under
+    /// `package_hash`, or `None` if every version has been disabled.
+    pub fn get_current_contract_version(
+        &self,
+        package_hash: ContractPackageHash,
+    ) -> Option<ContractVersionKey> {
+        self.get_contract_package(package_hash)?
+            .current_contract_version()
+    }
+
+    /// Returns the contract package stored under `package_hash`, including its versions, disabled
+    /// versions and groups.
+    pub fn get_contract_package(
+        &self,
+        package_hash: ContractPackageHash,
+    ) -> Option<ContractPackage> {
+        let contract_package_value: StoredValue = self
+            .query(None, Key::Hash(package_hash), &[])
+            .expect("should have contract package value");
+
+        if let StoredValue::ContractPackage(contract_package) = contract_package_value {
+            Some(contract_package)
+        } else {
+            None
+        }
+    }
+
     pub fn get_contract_wasm(&self, contract_hash: ContractHash) -> Option<ContractWasm> {
         let contract_value: StoredValue = self
             .query(None, contract_hash.into(), &[])
@@ -646,6 +1060,47 @@ where
         }
     }
 
+    /// Returns the sum of the staked amounts of all bids and all delegations held by the auction
+    /// contract as of the current post-state hash.  Amounts which are unbonding are not included.
+    pub fn total_staked(&self) -> U512 {
+        let bids: Bids = self.get_auction_value(BIDS_KEY);
+        let delegators: Delegators = self.get_auction_value(DELEGATORS_KEY);
+
+        let total_bids = bids
+            .values()
+            .fold(U512::zero(), |total, bid| total + bid.staked_amount);
+        delegators
+            .values()
+            .flat_map(|delegated_amounts| delegated_amounts.values())
+            .fold(total_bids, |total, amount| total + *amount)
+    }
+
+    /// Returns the entries of the auction's unbonding queue recorded under `public_key`, in the
+    /// order they were queued, as of the current post-state hash.
+    ///
+    /// Each entry holds the unbonded amount and the era from which it can be paid out.
+    pub fn get_unbonding_purses(&self, public_key: &PublicKey) -> Vec<UnbondingPurse> {
+        let mut unbonding_purses: UnbondingPurses = self.get_auction_value(UNBONDING_PURSES_KEY);
+        unbonding_purses.remove(public_key).unwrap_or_default()
+    }
+
+    fn get_auction_value<T: FromBytes + CLTyped>(&self, name: &str) -> T {
+        let contract = self
+            .get_contract(self.get_auction_contract_hash())
+            .expect("should have auction contract");
+        let key = contract
+            .named_keys()
+            .get(name)
+            .unwrap_or_else(|| panic!("auction contract should have named key {}", name));
+        self.query(None, *key, &[])
+            .expect("should query auction value")
+            .as_cl_value()
+            .cloned()
+            .expect("should be cl value")
+            .into_t()
+            .expect("should convert auction value")
+    }
+
     pub fn exec_costs(&self, index: usize) -> Vec<Gas> {
         let exec_response = self
             .get_exec_response(index)
@@ -662,6 +1117,27 @@ where
         exec_result.cost()
     }
 
+    /// Returns the gas charged by each host function during the last execution, summed per host
+    /// function name and sorted by name.
+    ///
+    /// Only populated if the builder's `EngineConfig` has the execution journal enabled.  The gas
+    /// for a call into another contract includes all gas consumed by the callee.
+    pub fn last_exec_instrumentation(&self) -> Vec<(String, u64)> {
+        let exec_response = self
+            .exec_responses
+            .last()
+            .expect("Expected to be called after run()");
+        let exec_result = exec_response.get(0).expect("should have result");
+
+        let mut gas_by_host_function: BTreeMap<String, u64> = BTreeMap::new();
+        for entry in exec_result.effect().journal.host_function_calls() {
+            *gas_by_host_function
+                .entry(entry.host_function().to_string())
+                .or_default() += entry.gas().as_u64();
+        }
+        gas_by_host_function.into_iter().collect()
+    }
+
     pub fn exec_error_message(&self, index: usize) -> Option<String> {
         let response = self.get_exec_response(index)?;
         Some(utils::get_error_message(response))
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_RECURSIVE_SUBCALL: &str = "recursive_subcall.wasm";
+const ARG_DEPTH: &str = "depth";
+const ARG_DISTINCT_CONTRACTS: &str = "distinct_contracts";
+const MAX_DEPTH: u32 = 12;
+
+fn recursive_subcall(builder: &mut InMemoryWasmTestBuilder, depth: u32, distinct_contracts: bool) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RECURSIVE_SUBCALL,
+        runtime_args! {
+            ARG_DEPTH => depth,
+            ARG_DISTINCT_CONTRACTS => distinct_contracts,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success();
+}
+
+/// Every subcall goes to the same contract, so all but the first one reuse the module cached
+/// earlier in the execution.
+pub fn recursive_subcall_cached(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recursive_subcall_cached");
+
+    // Minimum number of samples and measurement times to decrease the total time of this benchmark.
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for depth in &[1, MAX_DEPTH] {
+        group.bench_function(format!("depth={}", depth), |b| {
+            b.iter(|| recursive_subcall(&mut builder, *depth, false))
+        });
+    }
+
+    group.finish();
+}
+
+/// Baseline for `recursive_subcall_cached`: every subcall goes to a different contract with its
+/// own wasm hash, so each one parses its module from scratch.  Both groups install the same
+/// number of contracts per execution.
+pub fn recursive_subcall_distinct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recursive_subcall_distinct");
+
+    // Minimum number of samples and measurement times to decrease the total time of this benchmark.
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for depth in &[1, MAX_DEPTH] {
+        group.bench_function(format!("depth={}", depth), |b| {
+            b.iter(|| recursive_subcall(&mut builder, *depth, true))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    recursive_subcall_cached,
+    recursive_subcall_distinct
+);
+criterion_main!(benches);
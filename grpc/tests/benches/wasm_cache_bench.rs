@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::EngineConfig;
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const TARGET_ADDR: AccountHash = AccountHash::new([127; 32]);
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+fn transfer(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! { ARG_TARGET => TARGET_ADDR, ARG_AMOUNT => U512::one() },
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success();
+}
+
+pub fn wasm_cache_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wasm_cache");
+
+    // Minimum number of samples and measurement times to decrease the total time of this benchmark.
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    // The system contract cache is only used when the system contracts run as wasm.
+    let engine_config = EngineConfig::new().with_use_system_contracts(true);
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    group.bench_function("warm", |b| b.iter(|| transfer(&mut builder)));
+
+    group.bench_function("cold", |b| {
+        b.iter(|| {
+            builder.clear_wasm_caches();
+            transfer(&mut builder)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, wasm_cache_bench);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+use tempfile::TempDir;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ACCOUNT_2_ADDR: AccountHash = AccountHash::new([2u8; 32]);
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+fn transfer_to(builder: &mut LmdbWasmTestBuilder, target: AccountHash) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! { ARG_TARGET => target, ARG_AMOUNT => U512::from(1_000) },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_run_independent_scenarios_from_checkpoint() {
+    let data_dir = TempDir::new().expect("should create temp dir");
+    let mut builder = LmdbWasmTestBuilder::new(data_dir.path());
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let checkpoint = builder.save_checkpoint();
+
+    transfer_to(&mut builder, ACCOUNT_1_ADDR);
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_some());
+
+    builder.restore_checkpoint(checkpoint);
+    assert_eq!(builder.save_checkpoint(), checkpoint);
+    assert!(
+        builder.get_account(ACCOUNT_1_ADDR).is_none(),
+        "account created after the checkpoint should not be reachable"
+    );
+
+    transfer_to(&mut builder, ACCOUNT_2_ADDR);
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_none());
+    assert!(builder.get_account(ACCOUNT_2_ADDR).is_some());
+}
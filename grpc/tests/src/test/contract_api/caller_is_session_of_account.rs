@@ -0,0 +1,24 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::RuntimeArgs;
+
+const CONTRACT_CALLER_IS_SESSION_OF_ACCOUNT: &str = "caller_is_session_of_account.wasm";
+
+#[ignore]
+#[test]
+fn should_detect_session_of_account_as_immediate_caller() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CALLER_IS_SESSION_OF_ACCOUNT,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
@@ -0,0 +1,79 @@
+#[cfg(feature = "use-system-contracts")]
+use casper_engine_grpc_server::engine_server::ipc::DeployCode;
+#[cfg(feature = "use-system-contracts")]
+use casper_engine_test_support::internal::{utils, UpgradeRequestBuilder};
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+#[cfg(feature = "use-system-contracts")]
+use casper_execution_engine::core::engine_state::upgrade::ActivationPoint;
+#[cfg(feature = "use-system-contracts")]
+use casper_types::ProtocolVersion;
+use casper_types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_CALLER_IS_SYSTEM_CONTRACT: &str = "caller_is_system_contract.wasm";
+const ARG_VIA_MINT: &str = "via_mint";
+#[cfg(feature = "use-system-contracts")]
+const MODIFIED_SYSTEM_UPGRADER_CONTRACT_NAME: &str = "modified_system_upgrader.wasm";
+#[cfg(feature = "use-system-contracts")]
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+
+#[ignore]
+#[test]
+fn should_not_report_system_contract_caller_for_account_calls() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CALLER_IS_SYSTEM_CONTRACT,
+        runtime_args! { ARG_VIA_MINT => false },
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
+
+/// The mint only calls other contracts once it has been upgraded to one with a `forward_call`
+/// entry point, which needs the system contracts to run as Wasm.
+#[cfg(feature = "use-system-contracts")]
+#[ignore]
+#[test]
+fn should_report_mint_as_caller() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let new_protocol_version = ProtocolVersion::from_parts(2, 0, 0);
+
+    let mut upgrade_request = {
+        let bytes = utils::read_wasm_file_bytes(MODIFIED_SYSTEM_UPGRADER_CONTRACT_NAME);
+        let mut installer_code = DeployCode::new();
+        installer_code.set_code(bytes);
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(ProtocolVersion::V1_0_0)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_installer_code(installer_code)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CALLER_IS_SYSTEM_CONTRACT,
+        runtime_args! { ARG_VIA_MINT => true },
+    )
+    .with_protocol_version(new_protocol_version)
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+}
@@ -0,0 +1,61 @@
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+        DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::EngineConfig;
+use casper_types::{RuntimeArgs, KEY_HASH_LENGTH, U512};
+
+const CONTRACT_CONTRACT_IN_CALL_STACK: &str = "contract_in_call_stack.wasm";
+const CONTRACT_IN_CALL_STACK: &str = "host_function_contract_in_call_stack";
+
+fn run_contract_in_call_stack(engine_config: EngineConfig) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CONTRACT_IN_CALL_STACK,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_detect_reentrant_call() {
+    run_contract_in_call_stack(EngineConfig::new());
+}
+
+#[ignore]
+#[test]
+fn should_charge_contract_in_call_stack() {
+    let builder = run_contract_in_call_stack(EngineConfig::new().with_execution_journal(true));
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let charges: Vec<U512> = response[0]
+        .effect()
+        .journal
+        .host_function_calls()
+        .filter(|entry| entry.host_function() == CONTRACT_IN_CALL_STACK)
+        .map(|entry| entry.gas())
+        .collect();
+
+    // Session checks once, then the contract checks on its first entry and on reentry.
+    assert_eq!(charges.len(), 3);
+
+    let expected_charge = U512::from(DEFAULT_WASM_COSTS.regular)
+        + U512::from(DEFAULT_WASM_COSTS.memcpy) * U512::from(KEY_HASH_LENGTH);
+    for charge in charges {
+        assert_eq!(charge, expected_charge);
+    }
+}
@@ -7,7 +7,7 @@ use casper_engine_test_support::{
     DEFAULT_ACCOUNT_ADDR,
 };
 use casper_execution_engine::shared::transform::Transform;
-use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U512};
+use casper_types::{account::AccountHash, runtime_args, AccessRights, Key, RuntimeArgs, U512};
 
 const CONTRACT_CREATE_PURSE_01: &str = "create_purse_01.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
@@ -161,3 +161,46 @@ fn should_create_usable_purse() {
         "when created directly a purse has 0 balance"
     );
 }
+
+#[ignore]
+#[test]
+fn should_list_account_urefs() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! { "target" => ACCOUNT_1_ADDR, "amount" => *ACCOUNT_1_INITIAL_BALANCE},
+    )
+    .build();
+
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        runtime_args! { ARG_PURSE_NAME => TEST_PURSE_NAME },
+    )
+    .build();
+
+    let mut builder = WasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    builder.exec(exec_request_1).expect_success().commit();
+
+    builder.exec(exec_request_2).expect_success().commit();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account");
+    let test_purse = account_1
+        .named_keys()
+        .get(TEST_PURSE_NAME)
+        .expect("should have known key")
+        .into_uref()
+        .expect("should have uref");
+
+    let urefs = builder.get_account_urefs(ACCOUNT_1_ADDR);
+
+    assert_eq!(urefs.len(), 2, "{:?}", urefs);
+    assert_eq!(urefs[0], account_1.main_purse());
+    assert_eq!(urefs[0].access_rights(), AccessRights::READ_ADD_WRITE);
+    assert!(urefs.contains(&test_purse));
+}
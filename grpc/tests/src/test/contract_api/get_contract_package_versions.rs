@@ -0,0 +1,71 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{engine_state::Error, execution};
+use casper_types::{runtime_args, ContractPackageHash, Key, RuntimeArgs};
+
+const CONTRACT_GET_CONTRACT_PACKAGE_VERSIONS: &str = "get_contract_package_versions.wasm";
+const ARG_CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+
+#[ignore]
+#[test]
+fn should_get_enabled_contract_package_versions() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CONTRACT_PACKAGE_VERSIONS,
+        runtime_args! { ARG_CONTRACT_PACKAGE_HASH => Option::<ContractPackageHash>::None },
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_get_contract_package_versions_without_access() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CONTRACT_PACKAGE_VERSIONS,
+        runtime_args! { ARG_CONTRACT_PACKAGE_HASH => Option::<ContractPackageHash>::None },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .commit()
+        .expect_success();
+
+    let contract_package_hash = match builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(ARG_CONTRACT_PACKAGE_HASH)
+    {
+        Some(Key::Hash(hash)) => *hash,
+        other => panic!("should have contract package hash, got {:?}", other),
+    };
+
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CONTRACT_PACKAGE_VERSIONS,
+        runtime_args! { ARG_CONTRACT_PACKAGE_HASH => Some(contract_package_hash) },
+    )
+    .build();
+
+    builder.exec(exec_request_2).commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have exec response");
+    let error = response[0].as_error().expect("should have error");
+    assert_matches!(error, Error::Exec(execution::Error::ForgedReference(_)));
+}
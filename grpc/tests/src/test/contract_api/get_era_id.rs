@@ -0,0 +1,73 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    auction::{EraId, ARG_AMOUNT, METHOD_RUN_AUCTION, SYSTEM_ACCOUNT},
+    runtime_args, RuntimeArgs, U512,
+};
+
+const CONTRACT_GET_ERA_ID: &str = "get_era_id.wasm";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_AUCTION_BIDS: &str = "auction_bids.wasm";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ERA_ID_KEY: &str = "era_id";
+const SYSTEM_TRANSFER_AMOUNT: u64 = 250_000_000 + 1000;
+
+fn get_era_id(builder: &mut InMemoryWasmTestBuilder) -> EraId {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_ERA_ID,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).commit().expect_success();
+
+    let era_id_key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(ERA_ID_KEY)
+        .expect("should have era id");
+    builder
+        .query(None, era_id_key, &[])
+        .expect("should query era id")
+        .as_cl_value()
+        .cloned()
+        .expect("should be cl value")
+        .into_t()
+        .expect("should convert")
+}
+
+#[ignore]
+#[test]
+fn should_get_era_id_and_observe_it_advance() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            "target" => SYSTEM_ACCOUNT,
+            ARG_AMOUNT => U512::from(SYSTEM_TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit().expect_success();
+
+    let era_id_before = get_era_id(&mut builder);
+
+    let run_auction_request = ExecuteRequestBuilder::standard(
+        SYSTEM_ACCOUNT,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_RUN_AUCTION,
+        },
+    )
+    .build();
+    builder.exec(run_auction_request).commit().expect_success();
+
+    let era_id_after = get_era_id(&mut builder);
+    assert_eq!(era_id_after, era_id_before + 1);
+}
@@ -0,0 +1,118 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+        DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{
+    core::{
+        engine_state::{EngineConfig, Error},
+        execution,
+    },
+    shared::gas::Gas,
+};
+use casper_types::{bytesrepr::U64_SERIALIZED_LENGTH, runtime_args, RuntimeArgs, U512};
+
+const CONTRACT_GET_REMAINING_GAS: &str = "get_remaining_gas.wasm";
+const ARG_THRESHOLD: &str = "threshold";
+const ITERATIONS_KEY: &str = "iterations";
+const REMAINING_GAS_KEY: &str = "remaining_gas";
+const GAS_LIMIT: u64 = 1_000_000;
+const THRESHOLD: u64 = 100_000;
+const GET_REMAINING_GAS: &str = "host_function_get_remaining_gas";
+
+fn exec_get_remaining_gas(builder: &mut InMemoryWasmTestBuilder, threshold: u64) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_REMAINING_GAS,
+        runtime_args! { ARG_THRESHOLD => threshold },
+    )
+    .with_gas_limit(Gas::new(U512::from(GAS_LIMIT)))
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+fn read_named_value(builder: &InMemoryWasmTestBuilder, name: &str) -> u64 {
+    let key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(name)
+        .unwrap_or_else(|| panic!("should have named key {}", name));
+    builder
+        .query(None, key, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be cl value")
+        .into_t()
+        .expect("should convert")
+}
+
+#[ignore]
+#[test]
+fn should_stop_loop_when_remaining_gas_drops_below_threshold() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    exec_get_remaining_gas(&mut builder, THRESHOLD);
+    builder.expect_success();
+
+    let iterations = read_named_value(&builder, ITERATIONS_KEY);
+    let remaining_gas = read_named_value(&builder, REMAINING_GAS_KEY);
+    assert!(iterations > 0);
+    assert!(remaining_gas < THRESHOLD);
+}
+
+#[ignore]
+#[test]
+fn should_run_out_of_gas_without_threshold() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    // Remaining gas can never drop below zero, so the loop only ends at the gas limit.
+    exec_get_remaining_gas(&mut builder, 0);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert_matches!(
+        response[0].as_error(),
+        Some(Error::Exec(execution::Error::GasLimit))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_charge_get_remaining_gas() {
+    let mut builder =
+        InMemoryWasmTestBuilder::new_with_config(EngineConfig::new().with_execution_journal(true));
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    exec_get_remaining_gas(&mut builder, THRESHOLD);
+    builder.expect_success();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let charges: Vec<U512> = response[0]
+        .effect()
+        .journal
+        .host_function_calls()
+        .filter(|entry| entry.host_function() == GET_REMAINING_GAS)
+        .map(|entry| entry.gas())
+        .collect();
+
+    // The loop calls it once per iteration, plus once before the loop.
+    let iterations = read_named_value(&builder, ITERATIONS_KEY);
+    assert_eq!(charges.len() as u64, iterations + 1);
+
+    let expected_charge = U512::from(DEFAULT_WASM_COSTS.regular)
+        + U512::from(DEFAULT_WASM_COSTS.memcpy) * U512::from(U64_SERIALIZED_LENGTH);
+    for charge in charges {
+        assert_eq!(charge, expected_charge);
+    }
+}
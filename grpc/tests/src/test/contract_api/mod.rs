@@ -1,13 +1,25 @@
 mod account;
+mod caller_is_session_of_account;
+mod caller_is_system_contract;
+mod contract_in_call_stack;
 mod create_purse;
 mod get_arg;
 mod get_blocktime;
 mod get_caller;
+mod get_contract_package_versions;
+mod get_era_id;
 mod get_phase;
+mod get_remaining_gas;
+mod keccak256;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
+mod print;
+mod recursive_subcall;
+mod return_tuple3_urefs;
 mod revert;
+mod stored_bytes;
+mod strict_argument_checking;
 mod subcall;
 mod transfer;
 mod transfer_purse_to_account;
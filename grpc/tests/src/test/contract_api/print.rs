@@ -0,0 +1,50 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::RuntimeArgs;
+
+// Prints "hello" and then "world", each passed to `print` as a serialized `String`.
+const CONTRACT_WAT_PRINT: &str = r#"
+(module
+    (type (;0;) (func))
+    (type (;1;) (func (param i32 i32)))
+    (import "env" "memory" (memory (;0;) 1))
+    (import "env" "print" (func (;0;) (type 1)))
+    (func (;1;) (type 0)
+      i32.const 0
+      i32.const 9
+      call 0
+      i32.const 16
+      i32.const 9
+      call 0)
+    (export "call" (func 1))
+    (data (i32.const 0) "\05\00\00\00hello")
+    (data (i32.const 16) "\05\00\00\00world"))
+"#;
+
+#[ignore]
+#[test]
+fn should_capture_printed_output() {
+    let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_PRINT).expect("should parse");
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    assert!(builder.captured_output().is_empty());
+
+    for _ in 0..2 {
+        let exec_request = ExecuteRequestBuilder::module_bytes(
+            *DEFAULT_ACCOUNT_ADDR,
+            wasm_binary.clone(),
+            RuntimeArgs::new(),
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    assert_eq!(
+        builder.captured_output(),
+        vec!["hello", "world", "hello", "world"]
+    );
+}
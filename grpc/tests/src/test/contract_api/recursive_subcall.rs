@@ -0,0 +1,83 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{EngineConfig, Error},
+    execution,
+};
+use casper_types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_RECURSIVE_SUBCALL: &str = "recursive_subcall.wasm";
+const ARG_DEPTH: &str = "depth";
+const ARG_DISTINCT_CONTRACTS: &str = "distinct_contracts";
+const MAX_CALL_STACK_HEIGHT: u32 = 4;
+// Deeper than any limit used in these tests, to check the default configuration has none.
+const UNLIMITED_DEPTH: u32 = 32;
+
+fn run_recursive_subcall(engine_config: EngineConfig, depth: u32) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RECURSIVE_SUBCALL,
+        runtime_args! {
+            ARG_DEPTH => depth,
+            ARG_DISTINCT_CONTRACTS => false,
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn assert_runtime_stack_overflow(builder: &InMemoryWasmTestBuilder) {
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let error = response[0].as_error().expect("should have error");
+    assert_matches!(error, Error::Exec(execution::Error::RuntimeStackOverflow));
+}
+
+#[ignore]
+#[test]
+fn should_not_limit_call_stack_height_by_default() {
+    run_recursive_subcall(EngineConfig::new(), UNLIMITED_DEPTH).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_allow_call_stack_of_exactly_max_height() {
+    let engine_config =
+        EngineConfig::new().with_max_runtime_call_stack_height(MAX_CALL_STACK_HEIGHT);
+
+    // The session code is at the bottom of the call stack, so `MAX_CALL_STACK_HEIGHT - 1` nested
+    // contract calls make a call stack of exactly `MAX_CALL_STACK_HEIGHT` frames.
+    run_recursive_subcall(engine_config, MAX_CALL_STACK_HEIGHT - 1).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_fail_call_stack_past_max_height() {
+    let engine_config =
+        EngineConfig::new().with_max_runtime_call_stack_height(MAX_CALL_STACK_HEIGHT);
+
+    let builder = run_recursive_subcall(engine_config, MAX_CALL_STACK_HEIGHT);
+    assert_runtime_stack_overflow(&builder);
+}
+
+#[ignore]
+#[test]
+fn should_only_allow_session_code_with_max_height_of_one() {
+    let engine_config = EngineConfig::new().with_max_runtime_call_stack_height(1);
+
+    run_recursive_subcall(engine_config, 0).expect_success();
+
+    let builder = run_recursive_subcall(engine_config, 1);
+    assert_runtime_stack_overflow(&builder);
+}
@@ -0,0 +1,43 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::shared::stored_value::StoredValue;
+use casper_types::{CLValue, RuntimeArgs};
+
+const CONTRACT_RETURN_TUPLE3_UREFS: &str = "return_tuple3_urefs.wasm";
+const UREF_NAMES: [&str; 3] = ["first", "second", "third"];
+const WRITTEN_VALUE: u64 = 42;
+
+#[ignore]
+#[test]
+fn should_grant_access_to_urefs_returned_in_tuple3() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RETURN_TUPLE3_UREFS,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    for name in UREF_NAMES.iter() {
+        let key = *account
+            .named_keys()
+            .get(*name)
+            .unwrap_or_else(|| panic!("should have named key {}", name));
+        let value = builder.query(None, key, &[]).expect("should query uref");
+        let expected =
+            StoredValue::CLValue(CLValue::from_t(WRITTEN_VALUE).expect("should create CLValue"));
+        assert_eq!(value, expected, "unexpected value under {}", name);
+    }
+}
@@ -1,10 +1,18 @@
+use assert_matches::assert_matches;
+
 use casper_engine_test_support::{
     internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_types::RuntimeArgs;
+use casper_types::{
+    account::AccountHash, runtime_args, system_contract_errors::mint, ApiError, RuntimeArgs,
+};
 
 const REVERT_WASM: &str = "revert.wasm";
+const CONTRACT_REMOVE_ASSOCIATED_KEY: &str = "remove_associated_key.wasm";
+const CONTRACT_MINT_PURSE: &str = "mint_purse.wasm";
+const ARG_ACCOUNT: &str = "account";
+const UNKNOWN_ACCOUNT_ADDR: AccountHash = AccountHash::new([42; 32]);
 
 #[ignore]
 #[test]
@@ -16,5 +24,64 @@ fn should_revert() {
         .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
         .exec(exec_request)
         .commit()
-        .is_error();
+        .expect_revert(ApiError::User(100));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_user_error_zero() {
+    // Removing a key which isn't associated with the account reverts with `ApiError::User(0)`.
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REMOVE_ASSOCIATED_KEY,
+        runtime_args! { ARG_ACCOUNT => UNKNOWN_ACCOUNT_ADDR },
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_revert(ApiError::User(0));
+
+    assert_eq!(builder.last_exec_api_error(), Some(ApiError::User(0)));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_mint_error() {
+    let mut builder = mint_purse_as_non_system_account();
+
+    let api_error = builder.last_exec_api_error().expect("should have reverted");
+    assert_eq!(
+        api_error,
+        ApiError::from(mint::Error::InvalidNonEmptyPurseCreation)
+    );
+    assert_matches!(api_error, ApiError::Mint(_));
+
+    builder.expect_revert(ApiError::from(mint::Error::InvalidNonEmptyPurseCreation));
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "Expected execution to revert with ApiError::User(0)")]
+fn should_not_match_user_error_zero_against_mint_error() {
+    mint_purse_as_non_system_account().expect_revert(ApiError::User(0));
+}
+
+fn mint_purse_as_non_system_account() -> InMemoryWasmTestBuilder {
+    // Only the system account may create a purse with a non-zero balance, so the mint returns an
+    // error which the contract reverts with.
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_MINT_PURSE,
+        RuntimeArgs::default(),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
 }
@@ -0,0 +1,46 @@
+use casper_engine_test_support::internal::{InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST};
+use casper_execution_engine::shared::{
+    additive_map::AdditiveMap, stored_value::StoredValue, transform::Transform,
+};
+use casper_types::{AccessRights, CLValue, Key, URef, U512};
+
+const STORAGE_UREF_ADDR: [u8; 32] = [42; 32];
+const OTHER_UREF_ADDR: [u8; 32] = [43; 32];
+const MISSING_UREF_ADDR: [u8; 32] = [44; 32];
+
+fn write_value(builder: &mut InMemoryWasmTestBuilder, uref: URef, cl_value: CLValue) {
+    let mut effects = AdditiveMap::new();
+    effects.insert(
+        Key::URef(uref).normalize(),
+        Transform::Write(StoredValue::CLValue(cl_value)),
+    );
+    let prestate_hash = builder.get_post_state_hash();
+    builder.commit_effects(prestate_hash, effects);
+}
+
+#[ignore]
+#[test]
+fn should_get_stored_bytes() {
+    let storage_uref = URef::new(STORAGE_UREF_ADDR, AccessRights::READ_ADD_WRITE);
+    let other_uref = URef::new(OTHER_UREF_ADDR, AccessRights::READ_ADD_WRITE);
+    let missing_uref = URef::new(MISSING_UREF_ADDR, AccessRights::READ_ADD_WRITE);
+    let bytes = vec![0u8, 1, 2, 3, 255];
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    write_value(
+        &mut builder,
+        storage_uref,
+        CLValue::from_t(bytes.clone()).unwrap(),
+    );
+    write_value(
+        &mut builder,
+        other_uref,
+        CLValue::from_t(U512::from(42)).unwrap(),
+    );
+
+    assert_eq!(builder.get_stored_bytes(storage_uref), Some(bytes));
+    assert_eq!(builder.get_stored_bytes(other_uref), None);
+    assert_eq!(builder.get_stored_bytes(missing_uref), None);
+}
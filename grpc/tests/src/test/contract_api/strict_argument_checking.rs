@@ -0,0 +1,59 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{EngineConfig, Error},
+    execution,
+};
+use casper_types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_STRICT_ARGUMENT_CHECKING: &str = "strict_argument_checking.wasm";
+const ARG_MISMATCHED: &str = "mismatched";
+
+fn call_with_argument(engine_config: EngineConfig, mismatched: bool) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_STRICT_ARGUMENT_CHECKING,
+        runtime_args! { ARG_MISMATCHED => mismatched },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_accept_matching_argument_with_strict_argument_checking() {
+    let mut builder = call_with_argument(EngineConfig::new(), false);
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_reject_mismatched_argument_with_strict_argument_checking() {
+    let builder = call_with_argument(EngineConfig::new(), true);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert_matches!(
+        response[0].as_error(),
+        Some(Error::Exec(execution::Error::TypeMismatch(_)))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_accept_mismatched_argument_without_strict_argument_checking() {
+    let engine_config = EngineConfig::new().with_strict_argument_checking(false);
+    let mut builder = call_with_argument(engine_config, true);
+    builder.expect_success();
+}
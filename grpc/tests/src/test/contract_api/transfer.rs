@@ -1,14 +1,21 @@
+use std::convert::TryFrom;
+
 use lazy_static::lazy_static;
 
 use casper_engine_test_support::{
     internal::{
-        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
-        DEFAULT_RUN_GENESIS_REQUEST,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+        DEFAULT_SECP256K1_ACCOUNT_ADDR, DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY,
+        DEFAULT_SECP256K1_GENESIS_ACCOUNT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
-use casper_execution_engine::{core::engine_state::CONV_RATE, shared::motes::Motes};
-use casper_types::{account::AccountHash, runtime_args, ApiError, RuntimeArgs, U512};
+use casper_execution_engine::{
+    core::engine_state::{genesis::GenesisAccount, CONV_RATE},
+    shared::{motes::Motes, newtypes::Blake2bHash},
+};
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, U512};
 
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
 const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
@@ -75,6 +82,10 @@ fn should_transfer_to_account() {
         genesis_balance,
         initial_genesis_amount - gas_cost.value() - transfer_amount
     );
+    assert_eq!(
+        builder.get_public_key_balance(&DEFAULT_ACCOUNT_PUBLIC_KEY),
+        genesis_balance
+    );
 
     // Check account 1 balance
 
@@ -351,3 +362,90 @@ fn should_transfer_total_amount() {
         .expect_success()
         .finish();
 }
+
+#[ignore]
+#[test]
+fn should_query_balance_at_earlier_state_hash() {
+    let transfer = |builder: &mut InMemoryWasmTestBuilder| {
+        let exec_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            runtime_args! { ARG_TARGET => ACCOUNT_1_ADDR, ARG_AMOUNT => *TRANSFER_1_AMOUNT },
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+        Blake2bHash::try_from(builder.get_post_state_hash().as_slice())
+            .expect("should be a valid state hash")
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let genesis_hash = Blake2bHash::try_from(builder.get_genesis_hash().as_slice())
+        .expect("should be a valid state hash");
+
+    let first_hash = transfer(&mut builder);
+    transfer(&mut builder);
+
+    let account_1_key = Key::Account(ACCOUNT_1_ADDR);
+    assert!(builder
+        .query_at_state_hash(genesis_hash, account_1_key, &[])
+        .is_err());
+
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .main_purse();
+    assert_eq!(
+        builder.get_purse_balance_at(first_hash, account_1_purse),
+        *TRANSFER_1_AMOUNT
+    );
+    assert_eq!(
+        builder.get_purse_balance(account_1_purse),
+        *TRANSFER_1_AMOUNT * 2
+    );
+}
+
+#[ignore]
+#[test]
+fn should_transfer_from_secp256k1_account() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(DEFAULT_SECP256K1_GENESIS_ACCOUNT.clone());
+        tmp
+    };
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+
+    assert_eq!(
+        builder.get_public_key_balance(&DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY),
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE)
+    );
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_SECP256K1_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! { ARG_TARGET => ACCOUNT_1_ADDR, ARG_AMOUNT => *TRANSFER_1_AMOUNT },
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let gas_cost =
+        Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should convert gas to motes");
+
+    assert_eq!(
+        builder.get_public_key_balance(&DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY),
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - gas_cost.value() - *TRANSFER_1_AMOUNT
+    );
+
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .main_purse();
+    assert_eq!(
+        builder.get_purse_balance(account_1_purse),
+        *TRANSFER_1_AMOUNT
+    );
+}
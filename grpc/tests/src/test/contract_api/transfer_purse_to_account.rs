@@ -253,3 +253,38 @@ fn should_fail_when_sending_too_much_from_purse_to_account() {
         "Transfer Error incorrect"
     );
 }
+
+#[cfg(feature = "use-system-contracts")]
+#[ignore]
+#[test]
+fn should_not_charge_for_system_contract_calls_when_configured() {
+    use casper_execution_engine::core::engine_state::EngineConfig;
+
+    let transfer_cost = |engine_config: EngineConfig| {
+        let exec_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+            runtime_args! { "target" => ACCOUNT_1_ADDR, "amount" => *ACCOUNT_1_INITIAL_FUND },
+        )
+        .build();
+
+        let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+        builder
+            .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+            .exec(exec_request)
+            .expect_success()
+            .commit();
+        builder.last_exec_gas_cost()
+    };
+
+    let engine_config = EngineConfig::new().with_use_system_contracts(true);
+    let charged_cost = transfer_cost(engine_config);
+    let free_cost = transfer_cost(engine_config.with_zero_system_contract_gas(true));
+
+    assert!(
+        free_cost < charged_cost,
+        "calls into the mint should not be charged (free: {:?}, charged: {:?})",
+        free_cost,
+        charged_cost
+    );
+}
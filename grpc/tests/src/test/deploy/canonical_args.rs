@@ -0,0 +1,52 @@
+use casper_engine_test_support::internal::DeployItemBuilder;
+use casper_types::{bytesrepr, runtime_args, RuntimeArgs};
+
+fn unordered_args() -> RuntimeArgs {
+    runtime_args! {
+        "qwer" => Some(1i32),
+        "foo" => 1i32,
+        "bar" => "Foo",
+    }
+}
+
+#[ignore]
+#[test]
+fn should_canonicalize_args_added_before_the_flag() {
+    let deploy_item = DeployItemBuilder::new()
+        .with_empty_payment_bytes(unordered_args())
+        .with_session_bytes(vec![], unordered_args())
+        .with_canonical_args()
+        .build();
+
+    let session_args = deploy_item
+        .session
+        .into_runtime_args()
+        .expect("should deserialize session args");
+    let payment_args = deploy_item
+        .payment
+        .into_runtime_args()
+        .expect("should deserialize payment args");
+
+    assert!(session_args.is_canonical());
+    assert!(payment_args.is_canonical());
+    assert_eq!(
+        bytesrepr::serialize(session_args).expect("should serialize"),
+        bytesrepr::serialize(unordered_args().into_canonical().unwrap()).expect("should serialize")
+    );
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "should not have duplicated argument names")]
+fn should_reject_duplicated_args_with_canonical_args() {
+    let duplicated_args = runtime_args! {
+        "foo" => 1i32,
+        "foo" => 2i32,
+    };
+
+    DeployItemBuilder::new()
+        .with_canonical_args()
+        .with_empty_payment_bytes(RuntimeArgs::new())
+        .with_session_bytes(vec![], duplicated_args)
+        .build();
+}
@@ -0,0 +1,48 @@
+use casper_engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::shared::{gas::Gas, wasm_costs::WasmCosts};
+use casper_types::RuntimeArgs;
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+
+fn do_nothing_cost(wasm_costs: WasmCosts) -> Gas {
+    let run_genesis_request =
+        utils::create_run_genesis_request_with_wasm_costs(DEFAULT_ACCOUNTS.clone(), wasm_costs);
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_WASM,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&run_genesis_request)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder.last_exec_gas_cost()
+}
+
+#[ignore]
+#[test]
+fn should_charge_according_to_custom_wasm_costs_from_genesis() {
+    let default_cost = do_nothing_cost(*DEFAULT_WASM_COSTS);
+
+    let doubled_opcode_costs = WasmCosts {
+        opcodes_mul: DEFAULT_WASM_COSTS.opcodes_mul * 2,
+        ..*DEFAULT_WASM_COSTS
+    };
+    let doubled_cost = do_nothing_cost(doubled_opcode_costs);
+
+    assert!(
+        doubled_cost > default_cost,
+        "doubling opcode costs should increase the gas cost (default: {:?}, doubled: {:?})",
+        default_cost,
+        doubled_cost
+    );
+}
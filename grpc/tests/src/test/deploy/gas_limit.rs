@@ -0,0 +1,53 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{
+    core::{
+        engine_state::{Error, CONV_RATE},
+        execution,
+    },
+    shared::{gas::Gas, motes::Motes},
+};
+use casper_types::{RuntimeArgs, U512};
+
+const ENDLESS_LOOP_WASM: &str = "endless_loop.wasm";
+
+#[ignore]
+#[test]
+fn should_stop_session_at_configured_gas_limit() {
+    let gas_limit = Gas::new(U512::from(100_000));
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        ENDLESS_LOOP_WASM,
+        RuntimeArgs::default(),
+    )
+    .with_gas_limit(gas_limit)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let exec_result = &response[0];
+    assert_matches!(
+        exec_result.as_error(),
+        Some(Error::Exec(execution::Error::GasLimit))
+    );
+
+    // The session ran out of gas long before using up what was paid for it.
+    let paid_gas = Gas::from_motes(Motes::new(*DEFAULT_PAYMENT), CONV_RATE)
+        .expect("should convert payment to gas");
+    assert!(exec_result.cost() < paid_gas);
+}
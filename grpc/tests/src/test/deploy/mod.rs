@@ -1,3 +1,6 @@
+mod canonical_args;
+mod custom_wasm_costs;
+mod gas_limit;
 mod non_standard_payment;
 mod preconditions;
 mod stored_contracts;
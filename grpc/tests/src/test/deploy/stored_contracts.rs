@@ -1206,3 +1206,51 @@ fn should_execute_stored_payment_and_session_code_with_new_major_version() {
         .expect_success()
         .commit();
 }
+
+#[ignore]
+#[test]
+fn should_create_identical_contract_hashes_with_pinned_deploy_hash() {
+    const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored.wasm";
+    const DEPLOY_HASH: [u8; 32] = [55; 32];
+
+    let install_contract = |setup_deploys: usize| -> (Key, Key) {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+        // unrelated deploys which should not affect the addresses of the installed contract
+        for _ in 0..setup_deploys {
+            let exec_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                &format!("{}.wasm", DO_NOTHING_NAME),
+                RuntimeArgs::default(),
+            )
+            .build();
+            builder.exec(exec_request).expect_success().commit();
+        }
+
+        let exec_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            DO_NOTHING_STORED_CONTRACT_NAME,
+            RuntimeArgs::default(),
+        )
+        .with_deploy_hash(DEPLOY_HASH)
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account");
+        let contract_hash = *account
+            .named_keys()
+            .get(DO_NOTHING_CONTRACT_HASH_NAME)
+            .expect("should have contract hash");
+        let contract_package_hash = *account
+            .named_keys()
+            .get(DO_NOTHING_CONTRACT_PACKAGE_HASH_NAME)
+            .expect("should have contract package hash");
+        (contract_hash, contract_package_hash)
+    };
+
+    assert_eq!(install_contract(0), install_contract(0));
+    assert_eq!(install_contract(0), install_contract(2));
+}
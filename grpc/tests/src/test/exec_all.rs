@@ -0,0 +1,59 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{execute_request::ExecuteRequest, Error},
+    execution,
+};
+use casper_types::{ApiError, RuntimeArgs};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const REVERT_WASM: &str = "revert.wasm";
+
+fn exec_request(session_file: &str) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, session_file, RuntimeArgs::default())
+        .build()
+}
+
+#[ignore]
+#[test]
+fn should_exec_all_requests() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let results = builder
+        .exec_all(vec![
+            exec_request(DO_NOTHING_WASM),
+            exec_request(DO_NOTHING_WASM),
+        ])
+        .expect("should execute all requests");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(builder.get_exec_responses_count(), 2);
+}
+
+#[ignore]
+#[test]
+fn should_stop_exec_all_at_first_failure() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let (index, exec_result) = builder
+        .exec_all(vec![
+            exec_request(DO_NOTHING_WASM),
+            exec_request(REVERT_WASM),
+            exec_request(DO_NOTHING_WASM),
+        ])
+        .expect_err("should fail");
+
+    assert_eq!(index, 1);
+    assert_matches!(
+        exec_result.as_error(),
+        Some(Error::Exec(execution::Error::Revert(ApiError::User(100))))
+    );
+    // The request after the failing one is never executed.
+    assert_eq!(builder.get_exec_responses_count(), 2);
+}
@@ -0,0 +1,152 @@
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::{
+    execution_journal::{ExecutionJournal, JournalEntry},
+    EngineConfig,
+};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, URef, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+const GET_NAMED_ARG: &str = "host_get_named_arg";
+const TRANSFER_FROM_PURSE_TO_ACCOUNT: &str = "host_function_transfer_from_purse_to_account";
+
+fn run_transfer(engine_config: EngineConfig) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! { "target" => ACCOUNT_1_ADDR, "amount" => *DEFAULT_PAYMENT },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn last_journal(builder: &InMemoryWasmTestBuilder) -> ExecutionJournal {
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    response[0].effect().journal.clone()
+}
+
+#[ignore]
+#[test]
+fn should_not_record_execution_journal_by_default() {
+    let builder = run_transfer(EngineConfig::new());
+    assert!(last_journal(&builder).is_empty());
+    assert!(builder.last_exec_instrumentation().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_record_ordered_execution_journal_when_enabled() {
+    let builder = run_transfer(EngineConfig::new().with_execution_journal(true));
+    let journal = last_journal(&builder);
+
+    let host_functions: Vec<&str> = journal
+        .host_function_calls()
+        .map(|entry| entry.host_function())
+        .collect();
+
+    let first_get_named_arg = host_functions
+        .iter()
+        .position(|host_function| *host_function == GET_NAMED_ARG)
+        .expect("session should read its named args");
+    let transfer = host_functions
+        .iter()
+        .position(|host_function| *host_function == TRANSFER_FROM_PURSE_TO_ACCOUNT)
+        .expect("session should transfer to the target account");
+    assert!(
+        first_get_named_arg < transfer,
+        "named args should be read before the transfer: {:?}",
+        host_functions
+    );
+
+    let serialized = serde_json::to_string(&journal).expect("should serialize journal");
+    let deserialized: ExecutionJournal =
+        serde_json::from_str(&serialized).expect("should deserialize journal");
+    assert_eq!(deserialized, journal);
+}
+
+#[ignore]
+#[test]
+fn should_record_state_operations_and_transfers_when_enabled() {
+    let builder = run_transfer(EngineConfig::new().with_execution_journal(true));
+    let journal = last_journal(&builder);
+    let entries = journal.entries();
+
+    let source_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let target_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .main_purse();
+
+    let transfer = entries
+        .iter()
+        .position(|entry| match entry {
+            JournalEntry::Transfer {
+                source,
+                target,
+                amount,
+            } => {
+                let source = URef::from_formatted_str(source).expect("should parse source");
+                let target = URef::from_formatted_str(target).expect("should parse target");
+                source.addr() == source_purse.addr()
+                    && target.addr() == target_purse.addr()
+                    && *amount == *DEFAULT_PAYMENT
+            }
+            _ => false,
+        })
+        .expect("should record the transfer");
+    let transfer_host_function = entries
+        .iter()
+        .position(|entry| match entry {
+            JournalEntry::HostFunction(host_function_call) => {
+                host_function_call.host_function() == TRANSFER_FROM_PURSE_TO_ACCOUNT
+            }
+            _ => false,
+        })
+        .expect("should record the transfer host function");
+    assert!(
+        transfer < transfer_host_function,
+        "transfer should be recorded before its host function returns: {:?}",
+        entries
+    );
+
+    let new_account_key = Key::Account(ACCOUNT_1_ADDR).to_formatted_string();
+    assert!(entries.iter().any(|entry| match entry {
+        JournalEntry::Write { key } => *key == new_account_key,
+        _ => false,
+    }));
+    assert!(entries
+        .iter()
+        .any(|entry| matches!(entry, JournalEntry::Read { .. })));
+}
+
+#[ignore]
+#[test]
+fn should_report_gas_per_host_function_when_journal_enabled() {
+    let builder = run_transfer(EngineConfig::new().with_execution_journal(true));
+
+    let instrumentation = builder.last_exec_instrumentation();
+    assert!(instrumentation
+        .iter()
+        .any(|(host_function, _gas)| host_function == TRANSFER_FROM_PURSE_TO_ACCOUNT));
+
+    let host_function_gas: u64 = instrumentation.iter().map(|(_, gas)| gas).sum();
+    assert!(U512::from(host_function_gas) <= builder.last_exec_gas_cost().value());
+}
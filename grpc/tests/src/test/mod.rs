@@ -1,8 +1,11 @@
 mod check_transfer_success;
+mod checkpoint;
 mod contract_api;
 mod contract_context;
 mod counter;
 mod deploy;
+mod exec_all;
+mod execution_journal;
 mod explorer;
 mod groups;
 mod manage_groups;
@@ -1,40 +1,133 @@
 use casper_engine_test_support::{
-    internal::{
-        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
-        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
-    },
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_types::{runtime_args, RuntimeArgs};
+use casper_types::RuntimeArgs;
 
 const DO_NOTHING_WASM: &str = "do_nothing.wasm";
 
 // NOTE: Apparently rustc does not emit "start" when targeting wasm32
 // Ref: https://github.com/rustwasm/team/issues/108
+//
+// The start function writes a known value to memory, and `call` reverts unless it finds it there.
 const CONTRACT_WAT_WITH_START: &str = r#"
 (module
-    (memory (;0;) 1)
-    (export "memory" (memory 0))
     (type (;0;) (func))
+    (type (;1;) (func (param i32)))
+    (import "env" "memory" (memory (;0;) 1))
+    (import "env" "revert" (func (;0;) (type 1)))
+    (func (;1;) (type 0)
+      i32.const 0
+      i32.const 42
+      i32.store)
+    (func (;2;) (type 0)
+      i32.const 0
+      i32.load
+      i32.const 42
+      i32.ne
+      if
+        i32.const 65536
+        call 0
+      end)
+    (export "call" (func 2))
+    (start 1))
+"#;
+
+const CONTRACT_WAT_WITH_LOOPING_START: &str = r#"
+(module
+    (type (;0;) (func))
+    (import "env" "memory" (memory (;0;) 1))
     (func (;0;) (type 0)
+      loop
+        br 0
+      end)
+    (func (;1;) (type 0)
       nop)
+    (export "call" (func 1))
     (start 0))
 "#;
 
+// The start function stores a named key under `START_KEY_NAME`.
+const CONTRACT_WAT_WITH_PUT_KEY_START: &str = r#"
+(module
+    (type (;0;) (func))
+    (type (;1;) (func (param i32 i32 i32 i32)))
+    (import "env" "memory" (memory (;0;) 1))
+    (import "env" "put_key" (func (;0;) (type 1)))
+    (func (;1;) (type 0)
+      i32.const 0
+      i32.const 9
+      i32.const 16
+      i32.const 33
+      call 0)
+    (func (;2;) (type 0)
+      nop)
+    (export "call" (func 2))
+    (start 1)
+    (data (i32.const 0) "\05\00\00\00start")
+    (data (i32.const 16) "\01\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a"))
+"#;
+
+// As above, but the start function traps after storing the named key.
+const CONTRACT_WAT_WITH_TRAPPING_START: &str = r#"
+(module
+    (type (;0;) (func))
+    (type (;1;) (func (param i32 i32 i32 i32)))
+    (import "env" "memory" (memory (;0;) 1))
+    (import "env" "put_key" (func (;0;) (type 1)))
+    (func (;1;) (type 0)
+      i32.const 0
+      i32.const 9
+      i32.const 16
+      i32.const 33
+      call 0
+      unreachable)
+    (func (;2;) (type 0)
+      nop)
+    (export "call" (func 2))
+    (start 1)
+    (data (i32.const 0) "\05\00\00\00start")
+    (data (i32.const 16) "\01\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a\2a"))
+"#;
+
+const CONTRACT_WAT_EXPORTING_RESERVED_NAME: &str = r#"
+(module
+    (type (;0;) (func))
+    (import "env" "memory" (memory (;0;) 1))
+    (func (;0;) (type 0)
+      nop)
+    (export "call" (func 0))
+    (export "__casper_start" (func 0)))
+"#;
+
+const START_KEY_NAME: &str = "start";
+
 #[ignore]
 #[test]
-fn should_run_ee_890_gracefully_reject_start_node_in_session() {
+fn should_run_ee_890_start_node_before_session_entry_point() {
     let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_WITH_START).expect("should parse");
 
-    let deploy_1 = DeployItemBuilder::new()
-        .with_address(*DEFAULT_ACCOUNT_ADDR)
-        .with_session_bytes(wasm_binary, RuntimeArgs::new())
-        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT, })
-        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
-        .with_deploy_hash([123; 32])
-        .build();
+    let exec_request_1 =
+        ExecuteRequestBuilder::module_bytes(*DEFAULT_ACCOUNT_ADDR, wasm_binary, RuntimeArgs::new())
+            .with_deploy_hash([123; 32])
+            .build();
 
-    let exec_request_1 = ExecuteRequestBuilder::new().push_deploy(deploy_1).build();
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_run_ee_890_charge_start_node_in_session_against_gas_limit() {
+    let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_WITH_LOOPING_START).expect("should parse");
+
+    let exec_request_1 =
+        ExecuteRequestBuilder::module_bytes(*DEFAULT_ACCOUNT_ADDR, wasm_binary, RuntimeArgs::new())
+            .with_deploy_hash([123; 32])
+            .build();
 
     let result = InMemoryWasmTestBuilder::default()
         .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
@@ -43,7 +136,7 @@ fn should_run_ee_890_gracefully_reject_start_node_in_session() {
         .finish();
     let message = result.builder().exec_error_message(0).expect("should fail");
     assert!(
-        message.contains("UnsupportedWasmStart"),
+        message.contains("GasLimit"),
         "Error message {:?} does not contain expected pattern",
         message
     );
@@ -51,18 +144,14 @@ fn should_run_ee_890_gracefully_reject_start_node_in_session() {
 
 #[ignore]
 #[test]
-fn should_run_ee_890_gracefully_reject_start_node_in_payment() {
-    let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_WITH_START).expect("should parse");
+fn should_run_ee_890_charge_start_node_in_payment_against_gas_limit() {
+    let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_WITH_LOOPING_START).expect("should parse");
 
-    let deploy_1 = DeployItemBuilder::new()
-        .with_address(*DEFAULT_ACCOUNT_ADDR)
-        .with_session_code(DO_NOTHING_WASM, RuntimeArgs::new())
-        .with_payment_bytes(wasm_binary, RuntimeArgs::new())
-        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
-        .with_deploy_hash([123; 32])
-        .build();
-
-    let exec_request_1 = ExecuteRequestBuilder::new().push_deploy(deploy_1).build();
+    let exec_request_1 =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, RuntimeArgs::new())
+            .with_payment_bytes(wasm_binary, RuntimeArgs::new())
+            .with_deploy_hash([123; 32])
+            .build();
 
     let result = InMemoryWasmTestBuilder::default()
         .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
@@ -71,7 +160,68 @@ fn should_run_ee_890_gracefully_reject_start_node_in_payment() {
         .finish();
     let message = result.builder().exec_error_message(0).expect("should fail");
     assert!(
-        message.contains("UnsupportedWasmStart"),
+        message.contains("GasLimit"),
+        "Error message {:?} does not contain expected pattern",
+        message
+    );
+}
+
+fn exec_session_bytes(wasm_binary: Vec<u8>) -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::module_bytes(*DEFAULT_ACCOUNT_ADDR, wasm_binary, RuntimeArgs::new())
+            .with_deploy_hash([123; 32])
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn has_start_key(builder: &InMemoryWasmTestBuilder) -> bool {
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .contains_key(START_KEY_NAME)
+}
+
+#[ignore]
+#[test]
+fn should_run_ee_890_commit_effects_of_start_node() {
+    let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_WITH_PUT_KEY_START).expect("should parse");
+
+    let mut builder = exec_session_bytes(wasm_binary);
+    builder.expect_success();
+    assert!(has_start_key(&builder));
+}
+
+#[ignore]
+#[test]
+fn should_run_ee_890_not_commit_effects_of_trapping_start_node() {
+    let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_WITH_TRAPPING_START).expect("should parse");
+
+    let builder = exec_session_bytes(wasm_binary);
+    let message = builder.exec_error_message(0).expect("should fail");
+    assert!(
+        message.contains("Unreachable"),
+        "Error message {:?} does not contain expected pattern",
+        message
+    );
+    assert!(!has_start_key(&builder));
+}
+
+#[ignore]
+#[test]
+fn should_run_ee_890_reject_module_exporting_reserved_start_name() {
+    let wasm_binary = wabt::wat2wasm(CONTRACT_WAT_EXPORTING_RESERVED_NAME).expect("should parse");
+
+    let builder = exec_session_bytes(wasm_binary);
+    let message = builder.exec_error_message(0).expect("should fail");
+    assert!(
+        message.contains("ReservedExportName"),
         "Error message {:?} does not contain expected pattern",
         message
     );
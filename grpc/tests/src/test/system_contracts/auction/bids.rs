@@ -19,7 +19,9 @@ use casper_types::{
         ARG_VALIDATOR, BIDS_KEY,
     },
     bytesrepr::FromBytes,
-    runtime_args, CLTyped, ContractHash, PublicKey, RuntimeArgs, U512,
+    runtime_args,
+    system_contract_errors::auction as auction_error,
+    ApiError, CLTyped, ContractHash, PublicKey, RuntimeArgs, U512,
 };
 use std::iter::FromIterator;
 
@@ -184,6 +186,49 @@ fn should_run_add_bid() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_reduce_staked_amount_on_withdraw_bid() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let auction_hash = builder.get_auction_contract_hash();
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_PUBLIC_KEY => BID_ACCOUNT_PK,
+            ARG_ENTRY_POINT => ARG_ADD_BID,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    let withdraw_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => ARG_WITHDRAW_BID,
+            ARG_PUBLIC_KEY => BID_ACCOUNT_PK,
+            ARG_AMOUNT => U512::from(WITHDRAW_BID_AMOUNT_2),
+        },
+    )
+    .build();
+
+    builder.exec(add_bid_request).commit().expect_success();
+    builder.exec(withdraw_bid_request).commit().expect_success();
+
+    let bids: Bids = get_value(&mut builder, auction_hash, BIDS_KEY);
+    let active_bid = bids.get(&BID_ACCOUNT_PK).unwrap();
+    assert_eq!(
+        active_bid.staked_amount,
+        U512::from(ADD_BID_AMOUNT_1 - WITHDRAW_BID_AMOUNT_2)
+    );
+}
+
 #[ignore]
 #[test]
 fn should_run_delegate_and_undelegate() {
@@ -335,6 +380,20 @@ fn should_run_delegate_and_undelegate() {
         "{:?}",
         delegators
     );
+
+    assert_eq!(
+        builder.total_staked(),
+        U512::from(ADD_BID_AMOUNT_1 + DELEGATE_AMOUNT_1 + DELEGATE_AMOUNT_2 - UNDELEGATE_AMOUNT_1)
+    );
+
+    let unbond_list = builder.get_unbonding_purses(&BID_ACCOUNT_PK);
+    assert_eq!(unbond_list.len(), 1);
+    assert_eq!(unbond_list[0].origin, BID_ACCOUNT_PK);
+    assert_eq!(unbond_list[0].amount, U512::from(UNDELEGATE_AMOUNT_1));
+    assert_eq!(
+        unbond_list[0].era_of_withdrawal,
+        INITIAL_ERA_ID + DEFAULT_UNBONDING_DELAY,
+    );
 }
 
 #[ignore]
@@ -655,3 +714,124 @@ fn should_release_founder_stake() {
     assert_eq!(entry.funds_locked, None);
     assert_eq!(founding_validator, ACCOUNT_1_PK);
 }
+
+#[ignore]
+#[test]
+fn should_withdraw_bid_into_unbonding_purse() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_PUBLIC_KEY => BID_ACCOUNT_PK,
+            ARG_ENTRY_POINT => ARG_ADD_BID,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    builder.exec(add_bid_request).commit().expect_success();
+
+    super::assert_withdraw_result(
+        &mut builder,
+        BID_ACCOUNT_PK,
+        U512::from(WITHDRAW_BID_AMOUNT_2),
+    );
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_withdrawn_bid_after_unbonding_delay() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            "target" => SYSTEM_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_PUBLIC_KEY => BID_ACCOUNT_PK,
+            ARG_ENTRY_POINT => ARG_ADD_BID,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).commit().expect_success();
+    builder.exec(add_bid_request).commit().expect_success();
+
+    super::assert_unbond_available_after_delay(
+        &mut builder,
+        BID_ACCOUNT_PK,
+        U512::from(WITHDRAW_BID_AMOUNT_2),
+        DEFAULT_UNBONDING_DELAY,
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_withdraw_more_than_staked() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_PUBLIC_KEY => BID_ACCOUNT_PK,
+            ARG_ENTRY_POINT => ARG_ADD_BID,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    let withdraw_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => ARG_WITHDRAW_BID,
+            ARG_PUBLIC_KEY => BID_ACCOUNT_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1 + 1),
+        },
+    )
+    .build();
+
+    builder.exec(add_bid_request).commit().expect_success();
+    builder.exec(withdraw_bid_request).commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+
+    assert!(
+        error_message.contains(&format!(
+            "{:?}",
+            ApiError::from(auction_error::Error::InvalidAmount)
+        )),
+        error_message
+    );
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let bids: Bids = get_value(&mut builder, auction_hash, BIDS_KEY);
+    let active_bid = bids.get(&BID_ACCOUNT_PK).unwrap();
+    assert_eq!(active_bid.staked_amount, U512::from(ADD_BID_AMOUNT_1));
+}
@@ -1 +1,242 @@
 mod bids;
+mod multisig;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    account::{AccountHash, Weight},
+    auction::{
+        Bids, DelegationRate, EraId, UnbondingPurses, ARG_AMOUNT, ARG_DELEGATION_RATE,
+        ARG_PUBLIC_KEY, BIDS_KEY, ERA_ID_KEY, METHOD_ADD_BID, METHOD_RUN_AUCTION,
+        METHOD_WITHDRAW_BID, SYSTEM_ACCOUNT,
+    },
+    bytesrepr::FromBytes,
+    runtime_args, CLTyped, Key, PublicKey, RuntimeArgs, URef, U512,
+};
+
+const CONTRACT_AUCTION_BIDS: &str = "auction_bids.wasm";
+const CONTRACT_ADD_ASSOCIATED_KEY: &str = "add_associated_key.wasm";
+const CONTRACT_SET_KEY_THRESHOLDS: &str = "set_key_thresholds.wasm";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_ACCOUNT: &str = "account";
+const ARG_WEIGHT: &str = "weight";
+const ARG_KM_WEIGHT: &str = "km_weight";
+const ARG_DEP_WEIGHT: &str = "dep_weight";
+const UNBONDING_PURSES_KEY: &str = "unbonding_purses";
+const WITHDRAW_BID_RESULT_KEY: &str = "withdraw_bid_result";
+
+fn read_named_value<T>(builder: &InMemoryWasmTestBuilder, key: Key) -> T
+where
+    T: FromBytes + CLTyped,
+{
+    builder
+        .query(None, key, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be cl value")
+        .into_t()
+        .expect("should convert")
+}
+
+fn read_auction_value<T>(builder: &InMemoryWasmTestBuilder, name: &str) -> T
+where
+    T: FromBytes + CLTyped,
+{
+    let auction_hash = builder.get_auction_contract_hash();
+    let contract = builder
+        .get_contract(auction_hash)
+        .expect("should have auction contract");
+    let key = *contract
+        .named_keys()
+        .get(name)
+        .unwrap_or_else(|| panic!("auction should have named key {}", name));
+    read_named_value(builder, key)
+}
+
+/// Associates each of `associated_keys` with the account `account_hash` at the given weight, then
+/// requires `threshold` for both deployments and key management of that account.
+pub(crate) fn set_up_multisig_account(
+    builder: &mut InMemoryWasmTestBuilder,
+    account_hash: AccountHash,
+    associated_keys: &[(AccountHash, Weight)],
+    threshold: Weight,
+) {
+    for (associated_key, weight) in associated_keys {
+        let exec_request = ExecuteRequestBuilder::standard(
+            account_hash,
+            CONTRACT_ADD_ASSOCIATED_KEY,
+            runtime_args! {
+                ARG_ACCOUNT => *associated_key,
+                ARG_WEIGHT => u32::from(weight.value()),
+            },
+        )
+        .build();
+        builder.exec(exec_request).commit().expect_success();
+    }
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        account_hash,
+        CONTRACT_SET_KEY_THRESHOLDS,
+        runtime_args! {
+            ARG_KM_WEIGHT => u32::from(threshold.value()),
+            ARG_DEP_WEIGHT => u32::from(threshold.value()),
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit().expect_success();
+}
+
+/// Adds a bid for `public_key` from the main purse of the account `account_hash`, with the deploy
+/// signed by `authorization_keys`.
+///
+/// The result is committed but not checked, so callers can assert on failures as well.
+pub(crate) fn add_bid(
+    builder: &mut InMemoryWasmTestBuilder,
+    account_hash: AccountHash,
+    authorization_keys: &[AccountHash],
+    public_key: PublicKey,
+    amount: U512,
+    delegation_rate: DelegationRate,
+) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        account_hash,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_ADD_BID,
+            ARG_PUBLIC_KEY => public_key,
+            ARG_AMOUNT => amount,
+            ARG_DELEGATION_RATE => delegation_rate,
+        },
+    )
+    .with_authorization_keys(authorization_keys)
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+/// Withdraws `amount` from the bid of `public_key` using the default account and asserts that the
+/// purse returned by `withdraw_bid` is the unbonding purse recorded for exactly `amount`, and that
+/// the staked amount of the bid decreased by `amount`.
+///
+/// The withdrawn funds stay in the bid's bonding purse until the unbonding delay elapses, so this
+/// also asserts that the returned purse is still empty and the bonding purse balance is unchanged.
+/// See [`assert_unbond_available_after_delay`] for the payout.
+///
+/// Returns the unbonding purse.
+pub(crate) fn assert_withdraw_result(
+    builder: &mut InMemoryWasmTestBuilder,
+    public_key: PublicKey,
+    amount: U512,
+) -> URef {
+    let (staked_amount_before, bonding_purse) = {
+        let bids: Bids = read_auction_value(builder, BIDS_KEY);
+        let bid = bids
+            .get(&public_key)
+            .expect("should have bid before withdrawal");
+        (bid.staked_amount, bid.bonding_purse)
+    };
+    let bonding_purse_balance_before = builder.get_purse_balance(bonding_purse);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_WITHDRAW_BID,
+            ARG_PUBLIC_KEY => public_key,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit().expect_success();
+
+    let result_key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .named_keys()
+        .get(WITHDRAW_BID_RESULT_KEY)
+        .expect("should have withdraw_bid result");
+    let (returned_purse, returned_amount): (URef, U512) = read_named_value(builder, result_key);
+
+    let expected_amount = staked_amount_before - amount;
+    assert_eq!(returned_amount, expected_amount);
+
+    let bids: Bids = read_auction_value(builder, BIDS_KEY);
+    let bid = bids
+        .get(&public_key)
+        .expect("should have bid after withdrawal");
+    assert_eq!(bid.staked_amount, expected_amount);
+
+    let unbonding_purses: UnbondingPurses = read_auction_value(builder, UNBONDING_PURSES_KEY);
+    let unbonding_purse = unbonding_purses
+        .get(&public_key)
+        .and_then(|unbond_list| {
+            unbond_list
+                .iter()
+                .find(|unbonding_purse| unbonding_purse.purse.addr() == returned_purse.addr())
+        })
+        .expect("returned purse should be in the unbonding queue");
+    assert_eq!(unbonding_purse.origin, public_key);
+    assert_eq!(unbonding_purse.amount, amount);
+
+    assert_eq!(builder.get_purse_balance(returned_purse), U512::zero());
+    assert_eq!(
+        builder.get_purse_balance(bonding_purse),
+        bonding_purse_balance_before
+    );
+
+    returned_purse
+}
+
+fn run_auction(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        SYSTEM_ACCOUNT,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_RUN_AUCTION,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit().expect_success();
+}
+
+/// Withdraws `amount` from the bid of `public_key` as in [`assert_withdraw_result`], then runs the
+/// auction once per era and asserts that the unbonded funds are paid out only once `delay` eras
+/// have passed.
+///
+/// The system account must already hold enough funds to pay for running the auction.
+pub(crate) fn assert_unbond_available_after_delay(
+    builder: &mut InMemoryWasmTestBuilder,
+    public_key: PublicKey,
+    amount: U512,
+    delay: u64,
+) {
+    let era_id: EraId = read_auction_value(builder, ERA_ID_KEY);
+
+    let unbonding_purse = assert_withdraw_result(builder, public_key, amount);
+
+    let unbonding_purses: UnbondingPurses = read_auction_value(builder, UNBONDING_PURSES_KEY);
+    let era_of_withdrawal = unbonding_purses
+        .get(&public_key)
+        .and_then(|unbond_list| {
+            unbond_list
+                .iter()
+                .find(|entry| entry.purse.addr() == unbonding_purse.addr())
+        })
+        .expect("returned purse should be in the unbonding queue")
+        .era_of_withdrawal;
+    assert_eq!(era_of_withdrawal, era_id + delay);
+
+    for _ in 0..delay {
+        run_auction(builder);
+        assert_eq!(
+            builder.get_purse_balance(unbonding_purse),
+            U512::zero(),
+            "unbonded funds should not be available before the delay elapses"
+        );
+    }
+
+    run_auction(builder);
+    assert_eq!(builder.get_purse_balance(unbonding_purse), amount);
+}
@@ -0,0 +1,84 @@
+use casper_engine_test_support::{
+    internal::{
+        utils, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_SECP256K1_ACCOUNT_ADDR,
+        DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY, DEFAULT_SECP256K1_GENESIS_ACCOUNT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::genesis::GenesisAccount;
+use casper_types::{
+    account::Weight,
+    auction::{Bids, DelegationRate, BIDS_KEY},
+    U512,
+};
+
+use super::{add_bid, read_auction_value, set_up_multisig_account};
+
+const BID_AMOUNT: u64 = 95_000;
+const BID_DELEGATION_RATE: DelegationRate = 125;
+const THRESHOLD: u8 = 2;
+
+/// Sets up the secp256k1 default account so that deploys need both its own key and the ed25519
+/// key of the default account.
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(DEFAULT_SECP256K1_GENESIS_ACCOUNT.clone());
+        tmp
+    };
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+
+    set_up_multisig_account(
+        &mut builder,
+        *DEFAULT_SECP256K1_ACCOUNT_ADDR,
+        &[(*DEFAULT_ACCOUNT_ADDR, Weight::new(1))],
+        Weight::new(THRESHOLD),
+    );
+
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_add_bid_from_multisig_account_when_threshold_is_met() {
+    let mut builder = setup();
+
+    add_bid(
+        &mut builder,
+        *DEFAULT_SECP256K1_ACCOUNT_ADDR,
+        &[*DEFAULT_SECP256K1_ACCOUNT_ADDR, *DEFAULT_ACCOUNT_ADDR],
+        *DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY,
+        U512::from(BID_AMOUNT),
+        BID_DELEGATION_RATE,
+    );
+    builder.expect_success();
+
+    let bids: Bids = read_auction_value(&builder, BIDS_KEY);
+    let bid = bids
+        .get(&DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY)
+        .expect("should have bid");
+    assert_eq!(bid.staked_amount, U512::from(BID_AMOUNT));
+    assert_eq!(bid.delegation_rate, BID_DELEGATION_RATE);
+}
+
+#[ignore]
+#[test]
+fn should_not_add_bid_from_multisig_account_below_threshold() {
+    let mut builder = setup();
+
+    add_bid(
+        &mut builder,
+        *DEFAULT_SECP256K1_ACCOUNT_ADDR,
+        &[*DEFAULT_SECP256K1_ACCOUNT_ADDR],
+        *DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY,
+        U512::from(BID_AMOUNT),
+        BID_DELEGATION_RATE,
+    );
+    assert!(builder.is_error());
+
+    let bids: Bids = read_auction_value(&builder, BIDS_KEY);
+    assert!(!bids.contains_key(&DEFAULT_SECP256K1_ACCOUNT_PUBLIC_KEY));
+}
@@ -102,6 +102,17 @@ fn should_run_genesis() {
     assert_eq!(account_1_balance_actual, U512::from(ACCOUNT_1_BALANCE));
     assert_eq!(account_2_balance_actual, U512::from(ACCOUNT_2_BALANCE));
 
+    assert_eq!(
+        builder.initial_total_supply(),
+        U512::from(
+            ACCOUNT_1_BALANCE
+                + ACCOUNT_2_BALANCE
+                + ACCOUNT_1_BONDED_AMOUNT
+                + ACCOUNT_2_BONDED_AMOUNT
+        )
+    );
+    assert_eq!(builder.get_total_supply(), builder.initial_total_supply());
+
     let mint_contract_hash = builder.get_mint_contract_hash();
     let pos_contract_hash = builder.get_pos_contract_hash();
 
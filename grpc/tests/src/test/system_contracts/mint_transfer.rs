@@ -0,0 +1,83 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, system_contract_errors::mint, RuntimeArgs, URef, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ACCOUNT_1_INITIAL_BALANCE: u64 = 1_000_000;
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+/// Funds a new account from the default account and returns the main purses of both.
+fn setup(builder: &mut InMemoryWasmTestBuilder) -> (URef, URef) {
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(ACCOUNT_1_INITIAL_BALANCE)
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit().expect_success();
+
+    let default_account_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .main_purse();
+    (default_account_purse, account_1_purse)
+}
+
+#[ignore]
+#[test]
+fn should_mint_transfer_between_purses() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let (default_account_purse, account_1_purse) = setup(&mut builder);
+
+    let source_balance_before = builder.get_purse_balance(account_1_purse);
+    let target_balance_before = builder.get_purse_balance(default_account_purse);
+    let amount = U512::from(ACCOUNT_1_INITIAL_BALANCE / 2);
+
+    builder
+        .mint_transfer(account_1_purse, default_account_purse, amount)
+        .expect("should transfer");
+
+    assert_eq!(
+        builder.get_purse_balance(account_1_purse),
+        source_balance_before - amount
+    );
+    assert_eq!(
+        builder.get_purse_balance(default_account_purse),
+        target_balance_before + amount
+    );
+    // Transfers only move motes between purses
+    assert_eq!(builder.get_total_supply(), builder.initial_total_supply());
+}
+
+#[ignore]
+#[test]
+fn should_fail_mint_transfer_with_insufficient_funds() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let (default_account_purse, account_1_purse) = setup(&mut builder);
+
+    let amount = U512::from(ACCOUNT_1_INITIAL_BALANCE) + 1;
+
+    assert_eq!(
+        builder.mint_transfer(account_1_purse, default_account_purse, amount),
+        Err(mint::Error::InsufficientFunds)
+    );
+    assert_eq!(
+        builder.get_purse_balance(account_1_purse),
+        U512::from(ACCOUNT_1_INITIAL_BALANCE)
+    );
+}
@@ -3,8 +3,11 @@ mod auction_bonding;
 mod auction_install;
 mod genesis;
 mod mint_install;
+mod mint_transfer;
 mod pos_install;
 mod proof_of_stake;
 mod standard_payment;
 mod standard_payment_install;
+mod unchanged;
 mod upgrade;
+mod upgrade_named_keys_migration;
@@ -0,0 +1,78 @@
+use std::convert::TryInto;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::shared::newtypes::Blake2bHash;
+use casper_types::{
+    auction::{ARG_AMOUNT, METHOD_RUN_AUCTION, SYSTEM_ACCOUNT},
+    runtime_args, RuntimeArgs, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_AUCTION_BIDS: &str = "auction_bids.wasm";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const SYSTEM_TRANSFER_AMOUNT: u64 = 250_000_000 + 1000;
+
+/// Transfers enough funds to the system account for it to run the auction, and returns the post
+/// state hash before the transfer.
+fn transfer_to_system_account(builder: &mut InMemoryWasmTestBuilder) -> Blake2bHash {
+    let before: Blake2bHash = builder
+        .get_post_state_hash()
+        .as_slice()
+        .try_into()
+        .expect("should have valid post-state hash");
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            "target" => SYSTEM_ACCOUNT,
+            ARG_AMOUNT => U512::from(SYSTEM_TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit().expect_success();
+
+    before
+}
+
+#[ignore]
+#[test]
+fn should_leave_system_contracts_unchanged_after_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let before = transfer_to_system_account(&mut builder);
+
+    builder.assert_system_contracts_unchanged(before);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "system contracts should be unchanged")]
+fn should_detect_system_contract_changes_after_running_auction() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    transfer_to_system_account(&mut builder);
+
+    let before: Blake2bHash = builder
+        .get_post_state_hash()
+        .as_slice()
+        .try_into()
+        .expect("should have valid post-state hash");
+
+    let run_auction_request = ExecuteRequestBuilder::standard(
+        SYSTEM_ACCOUNT,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_RUN_AUCTION,
+        },
+    )
+    .build();
+    builder.exec(run_auction_request).commit().expect_success();
+
+    builder.assert_system_contracts_unchanged(before);
+}
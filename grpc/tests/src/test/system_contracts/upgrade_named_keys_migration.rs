@@ -0,0 +1,104 @@
+use casper_engine_grpc_server::engine_server::ipc::DeployCode;
+use casper_engine_test_support::internal::{
+    utils, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::{upgrade::ActivationPoint, SYSTEM_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, ProtocolVersion, RuntimeArgs};
+
+const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+const NAMED_KEYS_MIGRATION_UPGRADER_CONTRACT_NAME: &str = "named_keys_migration_upgrader.wasm";
+const ARG_NAMED_KEYS_MIGRATION: &str = "named_keys_migration";
+const ARG_ALLOWED_NAMES: &str = "allowed_names";
+const NAMED_KEYS_MIGRATION_APPEND_ALL: &str = "append_all";
+const NAMED_KEYS_MIGRATION_DROP_ALL: &str = "drop_all";
+const NAMED_KEYS_MIGRATION_ALLOW_LIST: &str = "allow_list";
+const PACKAGE_HASH_KEY_NAME: &str = "named_keys_migration_package_hash";
+
+/// Runs a protocol upgrade whose installer adds a version with named keys "a", "b" and "c" to a
+/// new contract package, then a second version with named key "d" using the migration named by
+/// `named_keys_migration`, with `allowed_names` as its allow-list.
+///
+/// Returns the names of the named keys of the second version.
+fn upgrade_with_named_keys_migration(
+    named_keys_migration: &str,
+    allowed_names: &[&str],
+) -> Vec<String> {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = {
+        let bytes = utils::read_wasm_file_bytes(NAMED_KEYS_MIGRATION_UPGRADER_CONTRACT_NAME);
+        let mut installer_code = DeployCode::new();
+        installer_code.set_code(bytes);
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_installer_code(installer_code)
+            .with_installer_args(runtime_args! {
+                ARG_NAMED_KEYS_MIGRATION => named_keys_migration.to_string(),
+                ARG_ALLOWED_NAMES => allowed_names
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect::<Vec<String>>(),
+            })
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(
+        upgrade_response.has_success(),
+        "upgrade_response expected success"
+    );
+
+    let package_hash = builder
+        .get_named_keys(Key::Account(SYSTEM_ACCOUNT_ADDR))
+        .get(PACKAGE_HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .expect("should have contract package hash");
+
+    let contract_hash = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package")
+        .current_contract_hash()
+        .expect("should have current contract");
+
+    builder
+        .get_named_keys(contract_hash.into())
+        .keys()
+        .cloned()
+        .collect()
+}
+
+#[ignore]
+#[test]
+fn should_append_all_named_keys_on_upgrade() {
+    let named_keys = upgrade_with_named_keys_migration(NAMED_KEYS_MIGRATION_APPEND_ALL, &[]);
+    assert_eq!(named_keys, vec!["a", "b", "c", "d"]);
+}
+
+#[ignore]
+#[test]
+fn should_drop_all_named_keys_on_upgrade() {
+    let named_keys = upgrade_with_named_keys_migration(NAMED_KEYS_MIGRATION_DROP_ALL, &[]);
+    assert_eq!(named_keys, vec!["d"]);
+}
+
+#[ignore]
+#[test]
+fn should_carry_forward_allowed_named_keys_on_upgrade() {
+    let named_keys =
+        upgrade_with_named_keys_migration(NAMED_KEYS_MIGRATION_ALLOW_LIST, &["a", "c", "e"]);
+    assert_eq!(named_keys, vec!["a", "c", "d"]);
+}
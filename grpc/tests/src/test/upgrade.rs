@@ -1,11 +1,19 @@
+use assert_matches::assert_matches;
+
 use casper_engine_test_support::{
-    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_execution_engine::shared::stored_value::StoredValue;
+use casper_execution_engine::{
+    core::{engine_state::Error, execution},
+    shared::stored_value::StoredValue,
+};
 use casper_types::{
     contracts::{ContractVersion, CONTRACT_INITIAL_VERSION},
-    runtime_args, CLValue, ContractPackageHash, RuntimeArgs,
+    runtime_args, CLValue, ContractHash, ContractPackageHash, ContractVersionKey, Key, RuntimeArgs,
 };
 
 const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored";
@@ -31,6 +39,13 @@ const ENTRY_POINT_ADD: &str = "add_named_purse";
 const ARG_CONTRACT_PACKAGE: &str = "contract_package";
 const ARG_VERSION: &str = "version";
 const ARG_NEW_PURSE_NAME: &str = "new_purse_name";
+const DISABLE_CONTRACT_VERSION_CONTRACT_NAME: &str = "disable_contract_version";
+const UPGRADED_HASH_KEY_NAME: &str = "end of upgrade";
+const ARG_CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const CURRENT_CONTRACT_HASH_CONTRACT_NAME: &str = "current_contract_hash";
+const ARG_EXPECTED_CONTRACT_HASH: &str = "expected_contract_hash";
+const GET_CONTRACT_PACKAGE_VERSIONS_CONTRACT_NAME: &str = "get_contract_package_versions";
 
 /// Performs define and execution of versioned contracts, calling them directly from hash
 #[ignore]
@@ -575,3 +590,315 @@ fn should_maintain_named_keys_across_upgrade() {
         );
     }
 }
+
+fn disable_contract_version(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+    contract_hash: ContractHash,
+) {
+    let exec_request = {
+        let contract_name = format!("{}.wasm", DISABLE_CONTRACT_VERSION_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            runtime_args! {
+                ARG_CONTRACT_PACKAGE_HASH => contract_package_hash,
+                ARG_CONTRACT_HASH => contract_hash,
+            },
+        )
+        .build()
+    };
+
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn assert_current_contract_hash(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+    expected_contract_hash: Option<ContractHash>,
+) {
+    let exec_request = {
+        let contract_name = format!("{}.wasm", CURRENT_CONTRACT_HASH_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            runtime_args! {
+                ARG_CONTRACT_PACKAGE_HASH => contract_package_hash,
+                ARG_EXPECTED_CONTRACT_HASH => expected_contract_hash,
+            },
+        )
+        .build()
+    };
+
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn exec_current_contract_hash(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+) {
+    let exec_request = {
+        let contract_name = format!("{}.wasm", CURRENT_CONTRACT_HASH_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            runtime_args! {
+                ARG_CONTRACT_PACKAGE_HASH => contract_package_hash,
+                ARG_EXPECTED_CONTRACT_HASH => Option::<ContractHash>::None,
+            },
+        )
+        .build()
+    };
+
+    builder.exec(exec_request).commit();
+}
+
+/// Asking for the current contract hash of something other than a contract package should fail
+/// with a type mismatch
+#[ignore]
+#[test]
+fn should_not_get_current_contract_hash_of_non_package() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    {
+        let exec_request = {
+            let contract_name = format!("{}.wasm", DO_NOTHING_STORED_CONTRACT_NAME);
+            ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                &contract_name,
+                RuntimeArgs::default(),
+            )
+            .build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let contract_hash: ContractHash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DO_NOTHING_HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .expect("should have contract hash");
+
+    exec_current_contract_hash(&mut builder, contract_hash);
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have exec response");
+    let error = response[0].as_error().expect("should have error");
+    assert_matches!(error, Error::Exec(execution::Error::TypeMismatch(_)));
+}
+
+/// Asking for the current contract hash of a package should require its access key, as for the
+/// package's versions
+#[ignore]
+#[test]
+fn should_not_get_current_contract_hash_without_access() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    // Creates a package and stores its hash, but not its access key, under the account
+    {
+        let exec_request = {
+            let contract_name = format!("{}.wasm", GET_CONTRACT_PACKAGE_VERSIONS_CONTRACT_NAME);
+            ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                &contract_name,
+                runtime_args! {
+                    ARG_CONTRACT_PACKAGE_HASH => Option::<ContractPackageHash>::None,
+                },
+            )
+            .build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let contract_package_hash: ContractPackageHash = match builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(ARG_CONTRACT_PACKAGE_HASH)
+    {
+        Some(Key::Hash(hash)) => *hash,
+        other => panic!("should have contract package hash, got {:?}", other),
+    };
+
+    exec_current_contract_hash(&mut builder, contract_package_hash);
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have exec response");
+    let error = response[0].as_error().expect("should have error");
+    assert_matches!(error, Error::Exec(execution::Error::ForgedReference(_)));
+}
+
+/// Disabling the newest version should make unversioned calls fall back to the previous enabled
+/// version, and disabling every version should leave the package without a current version
+#[ignore]
+#[test]
+fn should_fall_back_to_previous_enabled_version_after_disabling_latest() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    // Create contract package and store contract ver: 1.0.0 with "delegate" entry function
+    {
+        let exec_request = {
+            let contract_name = format!("{}.wasm", DO_NOTHING_STORED_CONTRACT_NAME);
+            ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                &contract_name,
+                RuntimeArgs::default(),
+            )
+            .build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    // Upgrade version having call to create_purse_01
+    {
+        let exec_request = {
+            let contract_name = format!("{}.wasm", DO_NOTHING_STORED_UPGRADER_CONTRACT_NAME);
+            ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                &contract_name,
+                RuntimeArgs::default(),
+            )
+            .build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let get_hash = |name: &str| {
+        account
+            .named_keys()
+            .get(name)
+            .and_then(|key| key.into_hash())
+            .unwrap_or_else(|| panic!("should have hash under {}", name))
+    };
+    let package_hash: ContractPackageHash = get_hash(DO_NOTHING_PACKAGE_HASH_KEY_NAME);
+    let initial_hash: ContractHash = get_hash(DO_NOTHING_HASH_KEY_NAME);
+    let upgraded_hash: ContractHash = get_hash(UPGRADED_HASH_KEY_NAME);
+
+    let protocol_version_major = DEFAULT_PROTOCOL_VERSION.value().major;
+    let initial_version_key = ContractVersionKey::new(protocol_version_major, INITIAL_VERSION);
+    let upgraded_version_key = ContractVersionKey::new(protocol_version_major, UPGRADED_VERSION);
+
+    assert_eq!(
+        builder.get_current_contract_version(package_hash),
+        Some(upgraded_version_key)
+    );
+    assert_current_contract_hash(&mut builder, package_hash, Some(upgraded_hash));
+
+    disable_contract_version(&mut builder, package_hash, upgraded_hash);
+
+    assert_eq!(
+        builder.get_current_contract_version(package_hash),
+        Some(initial_version_key)
+    );
+    assert_current_contract_hash(&mut builder, package_hash, Some(initial_hash));
+
+    // Calling without a version should now resolve to the initial version, which has no effects
+    {
+        let args = runtime_args! {
+            PURSE_NAME_ARG_NAME => PURSE_1,
+        };
+        let exec_request = {
+            ExecuteRequestBuilder::versioned_contract_call_by_hash_key_name(
+                *DEFAULT_ACCOUNT_ADDR,
+                DO_NOTHING_PACKAGE_HASH_KEY_NAME,
+                None,
+                ENTRY_FUNCTION_NAME,
+                args,
+            )
+            .build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    assert!(
+        account.named_keys().get(PURSE_1).is_none(),
+        "disabled version should not have been called",
+    );
+
+    disable_contract_version(&mut builder, package_hash, initial_hash);
+
+    assert_eq!(builder.get_current_contract_version(package_hash), None);
+    assert_current_contract_hash(&mut builder, package_hash, None);
+}
+
+/// Disabling a version should record it in the package's disabled versions while keeping it among
+/// the package's versions
+#[ignore]
+#[test]
+fn should_record_disabled_version_in_contract_package() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for contract_name in &[
+        DO_NOTHING_STORED_CONTRACT_NAME,
+        DO_NOTHING_STORED_UPGRADER_CONTRACT_NAME,
+    ] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &format!("{}.wasm", contract_name),
+            RuntimeArgs::default(),
+        )
+        .build();
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let get_hash = |name: &str| {
+        account
+            .named_keys()
+            .get(name)
+            .and_then(|key| key.into_hash())
+            .unwrap_or_else(|| panic!("should have hash under {}", name))
+    };
+    let package_hash: ContractPackageHash = get_hash(DO_NOTHING_PACKAGE_HASH_KEY_NAME);
+    let upgraded_hash: ContractHash = get_hash(UPGRADED_HASH_KEY_NAME);
+
+    let protocol_version_major = DEFAULT_PROTOCOL_VERSION.value().major;
+    let initial_version_key = ContractVersionKey::new(protocol_version_major, INITIAL_VERSION);
+    let upgraded_version_key = ContractVersionKey::new(protocol_version_major, UPGRADED_VERSION);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert!(contract_package.disabled_versions().is_empty());
+
+    disable_contract_version(&mut builder, package_hash, upgraded_hash);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    let versions: Vec<ContractVersionKey> = contract_package.versions().keys().copied().collect();
+    assert_eq!(versions, vec![initial_version_key, upgraded_version_key]);
+    assert!(contract_package
+        .disabled_versions()
+        .contains(&upgraded_version_key));
+    assert_eq!(contract_package.disabled_versions().len(), 1);
+    assert!(!contract_package.is_version_enabled(upgraded_version_key));
+    assert!(contract_package.is_version_enabled(initial_version_key));
+}
@@ -9,10 +9,12 @@ use core::mem::MaybeUninit;
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes},
+    auction::EraId,
+    bytesrepr::{self, FromBytes, U64_SERIALIZED_LENGTH},
     contracts::{ContractVersion, NamedKeys},
     ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
-    RuntimeArgs, URef, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    RuntimeArgs, URef, BLOCKTIME_SERIALIZED_LENGTH, KECCAK256_DIGEST_LENGTH,
+    PHASE_SERIALIZED_LENGTH,
 };
 
 use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -206,6 +208,18 @@ pub fn get_blocktime() -> BlockTime {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns the current [`EraId`], as recorded by the auction contract.
+pub fn get_era_id() -> EraId {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_era_id(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
 /// Returns the current [`Phase`].
 pub fn get_phase() -> Phase {
     let dest_non_null_ptr = contract_api::alloc_bytes(PHASE_SERIALIZED_LENGTH);
@@ -305,6 +319,65 @@ pub fn is_valid_uref(uref: URef) -> bool {
     result != 0
 }
 
+/// Returns `true` if the given contract is one of the callers of the currently executing contract.
+///
+/// This can be used to guard against reentrancy: a contract which finds its own hash in the call
+/// stack is being called back into.
+pub fn contract_in_call_stack(contract_hash: ContractHash) -> bool {
+    let (contract_hash_ptr, contract_hash_size, _bytes) = contract_api::to_ptr(contract_hash);
+    let result = unsafe { ext_ffi::contract_in_call_stack(contract_hash_ptr, contract_hash_size) };
+    result != 0
+}
+
+/// Returns the Keccak-256 hash of `input`.
+pub fn keccak256<T: AsRef<[u8]>>(input: T) -> [u8; KECCAK256_DIGEST_LENGTH] {
+    let input = input.as_ref();
+    let mut digest = [0u8; KECCAK256_DIGEST_LENGTH];
+    let result = unsafe {
+        ext_ffi::keccak256(
+            input.as_ptr(),
+            input.len(),
+            digest.as_mut_ptr(),
+            digest.len(),
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    digest
+}
+
+/// Returns `true` if the immediate caller of the currently executing contract is session code
+/// running in the context of the given account.
+///
+/// This is `false` when called from session code itself, and when the immediate caller is another
+/// contract, even if that contract was in turn called by the account's session.
+///
+/// The immediate caller may be either the deploy's session code or a stored entry point of type
+/// [`EntryPointType::Session`](casper_types::EntryPointType::Session), since both run in the
+/// context of the account; this function doesn't distinguish between them.
+pub fn caller_is_session_of_account(account_hash: AccountHash) -> bool {
+    let (account_hash_ptr, account_hash_size, _bytes) = contract_api::to_ptr(account_hash);
+    let result =
+        unsafe { ext_ffi::caller_is_session_of_account(account_hash_ptr, account_hash_size) };
+    result != 0
+}
+
+/// Returns the amount of gas remaining before the gas limit of the current execution is reached.
+///
+/// Long-running code can check this to stop gracefully rather than running out of gas.  The
+/// returned value already accounts for the cost of this call.
+pub fn get_remaining_gas() -> u64 {
+    let dest_non_null_ptr = contract_api::alloc_bytes(U64_SERIALIZED_LENGTH);
+    let bytes = unsafe {
+        ext_ffi::get_remaining_gas(dest_non_null_ptr.as_ptr());
+        Vec::from_raw_parts(
+            dest_non_null_ptr.as_ptr(),
+            U64_SERIALIZED_LENGTH,
+            U64_SERIALIZED_LENGTH,
+        )
+    };
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
@@ -6,7 +6,7 @@ use core::{convert::From, mem::MaybeUninit};
 use casper_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    contracts::{ContractVersion, EntryPoints, NamedKeys},
+    contracts::{ContractVersion, ContractVersions, EntryPoints, NamedKeys, NamedKeysMigration},
     AccessRights, ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, URef,
     UREF_SERIALIZED_LENGTH,
 };
@@ -286,6 +286,8 @@ pub fn remove_contract_user_group(
 /// Add a new version of a contract to the contract stored at the given
 /// `Key`. Note that this contract must have been created by
 /// `create_contract` or `create_contract_package_at_hash` first.
+///
+/// All named keys of the current version are carried forward to the new one.
 pub fn add_contract_version(
     contract_package_hash: ContractPackageHash,
     entry_points: EntryPoints,
@@ -324,6 +326,52 @@ pub fn add_contract_version(
     (contract_hash, contract_version)
 }
 
+/// Add a new version of a contract to the contract stored at the given
+/// `Key`, carrying forward the named keys of the current version as
+/// described by `named_keys_migration`.
+pub fn add_contract_version_with_named_keys_migration(
+    contract_package_hash: ContractPackageHash,
+    entry_points: EntryPoints,
+    named_keys: NamedKeys,
+    named_keys_migration: NamedKeysMigration,
+) -> (ContractHash, ContractVersion) {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes1) =
+        contract_api::to_ptr(contract_package_hash);
+    let (entry_points_ptr, entry_points_size, _bytes4) = contract_api::to_ptr(entry_points);
+    let (named_keys_ptr, named_keys_size, _bytes5) = contract_api::to_ptr(named_keys);
+    let (named_keys_migration_ptr, named_keys_migration_size, _bytes6) =
+        contract_api::to_ptr(named_keys_migration);
+
+    let mut output_ptr = vec![0u8; Key::max_serialized_length()];
+    let mut total_bytes: usize = 0;
+
+    let mut contract_version: ContractVersion = 0;
+
+    let ret = unsafe {
+        ext_ffi::add_contract_version_with_named_keys_migration(
+            contract_package_hash_ptr,
+            contract_package_hash_size,
+            &mut contract_version as *mut ContractVersion,
+            entry_points_ptr,
+            entry_points_size,
+            named_keys_ptr,
+            named_keys_size,
+            named_keys_migration_ptr,
+            named_keys_migration_size,
+            output_ptr.as_mut_ptr(),
+            output_ptr.len(),
+            &mut total_bytes as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(_) => {}
+        Err(e) => revert(e),
+    }
+    output_ptr.truncate(total_bytes);
+    let contract_hash = bytesrepr::deserialize(output_ptr).unwrap_or_revert();
+    (contract_hash, contract_version)
+}
+
 /// Disable a version of a contract from the contract stored at the given
 /// `Key`. That version of the contract will no longer be callable by
 /// `call_versioned_contract`. Note that this contract must have been created by
@@ -347,3 +395,50 @@ pub fn disable_contract_version(
 
     api_error::result_from(result)
 }
+
+/// Returns the hash of the newest enabled version of the contract stored in the given contract
+/// package, or `None` if all of its versions are disabled.  The caller must have access to the
+/// contract package.
+pub fn current_contract_hash(contract_package_hash: ContractPackageHash) -> Option<ContractHash> {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes) =
+        contract_api::to_ptr(contract_package_hash);
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::current_contract_hash(
+                contract_package_hash_ptr,
+                contract_package_hash_size,
+                value_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { value_size.assume_init() }
+    };
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    bytesrepr::deserialize(value_bytes).unwrap_or_revert()
+}
+
+/// Returns the enabled versions of the contract package stored at the given hash, keyed by
+/// version.  The caller must have access to the contract package.
+pub fn get_contract_package_versions(
+    contract_package_hash: ContractPackageHash,
+) -> ContractVersions {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes) =
+        contract_api::to_ptr(contract_package_hash);
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::get_contract_package_versions(
+                contract_package_hash_ptr,
+                contract_package_hash_size,
+                value_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { value_size.assume_init() }
+    };
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    bytesrepr::deserialize(value_bytes).unwrap_or_revert()
+}
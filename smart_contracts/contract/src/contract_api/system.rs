@@ -64,6 +64,27 @@ pub fn get_auction() -> ContractHash {
     get_system_contract(SystemContractType::Auction)
 }
 
+/// Returns the refund purse set in the Proof of Stake contract for the current deploy, or `None`
+/// if no refund purse has been set.
+pub fn get_refund_purse() -> Option<URef> {
+    let value_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_refund_purse(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    bytesrepr::deserialize(value_bytes).unwrap_or_revert()
+}
+
+/// Returns `true` if the immediate caller of the currently executing contract is the given system
+/// contract.
+pub fn caller_is_system_contract(system_contract: SystemContractType) -> bool {
+    let system_contract_index = system_contract.into();
+    let result = unsafe { ext_ffi::caller_is_system_contract(system_contract_index) };
+    result != 0
+}
+
 /// Creates a new empty purse and returns its [`URef`].
 pub fn create_purse() -> URef {
     let purse_non_null_ptr = contract_api::alloc_bytes(UREF_SERIALIZED_LENGTH);
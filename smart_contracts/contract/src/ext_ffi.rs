@@ -471,6 +471,40 @@ extern "C" {
         output_size: usize,
         bytes_written_ptr: *mut usize,
     ) -> i32;
+    /// Adds new contract version to a contract package, carrying forward the named keys of the
+    /// current version as described by a [`casper_types::contracts::NamedKeysMigration`].
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `version_ptr` - output parameter where new version assigned by host is set
+    /// * `entry_points_ptr` - pointer to serialized [`casper_types::EntryPoints`]
+    /// * `entry_points_size` - size of serialized [`casper_types::EntryPoints`]
+    /// * `named_keys_ptr` - pointer to serialized [`casper_types::contracts::NamedKeys`]
+    /// * `named_keys_size` - size of serialized [`casper_types::contracts::NamedKeys`]
+    /// * `named_keys_migration_ptr` - pointer to serialized
+    ///   [`casper_types::contracts::NamedKeysMigration`]
+    /// * `named_keys_migration_size` - size of serialized
+    ///   [`casper_types::contracts::NamedKeysMigration`]
+    /// * `output_ptr` - pointer to a memory where host assigned contract hash is set to
+    /// * `output_size` - size of memory area that host can write to
+    /// * `bytes_written_ptr` - pointer to a value where host will set a number of bytes written to
+    ///   the `output_size` pointer
+    pub fn add_contract_version_with_named_keys_migration(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        version_ptr: *const u32,
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        named_keys_migration_ptr: *const u8,
+        named_keys_migration_size: usize,
+        output_ptr: *mut u8,
+        output_size: usize,
+        bytes_written_ptr: *mut usize,
+    ) -> i32;
     /// Disables contract in a contract package. Returns non-zero standard error for a failure,
     /// otherwise a zero indicates success.
     ///
@@ -626,6 +660,96 @@ extern "C" {
         urefs_ptr: *const u8,
         urefs_size: usize,
     ) -> i32;
+    /// Queries the Proof of Stake contract for the refund purse of the current deploy. The result
+    /// is written to the host buffer serialized as `Option<URef>`, and its size is written to
+    /// `output_size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_size` - pointer to a value where the size of the result will be written
+    pub fn get_refund_purse(output_size: *mut usize) -> i32;
+    /// Checks if the immediate caller of the currently executing contract is the given system
+    /// contract.  Returns `1` if it is, or `0` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `system_contract_index` - index of the system contract, as defined by
+    ///   [`casper_types::SystemContractType`]
+    pub fn caller_is_system_contract(system_contract_index: u32) -> i32;
+    /// Writes the hash of the newest enabled contract version of the given contract package to
+    /// the host buffer, serialized as `Option<ContractHash>`, and writes its size to
+    /// `output_size`.  The caller must have access to the contract package.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form
+    /// * `output_size` - pointer to a value where the size of the result will be written
+    pub fn current_contract_hash(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+    /// Checks if the given contract is one of the callers of the currently executing contract.
+    /// Returns `1` if it is, or `0` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_hash_ptr` - pointer to serialized contract hash
+    /// * `contract_hash_size` - size of contract hash in serialized form
+    pub fn contract_in_call_stack(contract_hash_ptr: *const u8, contract_hash_size: usize) -> i32;
+    /// Computes the Keccak-256 hash of the given input and writes it to the output buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_ptr` - pointer to the input bytes
+    /// * `in_size` - size of the input bytes
+    /// * `out_ptr` - pointer to the output buffer
+    /// * `out_size` - size of the output buffer, which must be exactly 32 bytes
+    pub fn keccak256(in_ptr: *const u8, in_size: usize, out_ptr: *mut u8, out_size: usize) -> i32;
+    /// Writes the enabled contract versions of the given contract package to the host buffer,
+    /// serialized as `ContractVersions`, and writes its size to `output_size`.  The caller must
+    /// have access to the contract package.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form
+    /// * `output_size` - pointer to a value where the size of the result will be written
+    pub fn get_contract_package_versions(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+    /// Writes the current era id to the host buffer, serialized as `EraId`, and writes its size
+    /// to `output_size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_size` - pointer to a value where the size of the result will be written
+    pub fn get_era_id(output_size: *mut usize) -> i32;
+    /// Checks if the immediate caller of the currently executing contract is session code running
+    /// in the context of the given account.  Returns `1` if it is, or `0` otherwise.
+    ///
+    /// Stored entry points of type [`casper_types::EntryPointType::Session`] run in the context of
+    /// the calling account, so they count as session code of that account too.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_hash_ptr` - pointer to serialized account hash
+    /// * `account_hash_size` - size of account hash in serialized form
+    pub fn caller_is_session_of_account(
+        account_hash_ptr: *const u8,
+        account_hash_size: usize,
+    ) -> i32;
+    /// Writes the gas remaining before the gas limit of the current execution is reached, as a
+    /// serialized `u64`, to the given pointer in wasm memory.  The cost of this call is charged
+    /// before the remaining gas is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn get_remaining_gas(dest_ptr: *const u8);
 
     /// Prints data directly to stanadard output on the host.
     ///
@@ -72,8 +72,9 @@ fn withdraw_bid() {
         ARG_PUBLIC_KEY => public_key,
     };
 
-    let (_purse, _amount): (URef, U512) =
-        runtime::call_contract(auction, METHOD_WITHDRAW_BID, args);
+    let result: (URef, U512) = runtime::call_contract(auction, METHOD_WITHDRAW_BID, args);
+    let uref = storage::new_uref(result);
+    runtime::put_key("withdraw_bid_result", uref.into());
 }
 
 fn delegate() {
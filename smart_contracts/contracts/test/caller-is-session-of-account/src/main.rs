@@ -0,0 +1,137 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    account::AccountHash,
+    contracts::{EntryPoint, EntryPoints, Parameter},
+    runtime_args, ApiError, CLType, CLTyped, ContractHash, EntryPointAccess, EntryPointType,
+    RuntimeArgs,
+};
+
+const ENTRY_POINT_CHECK: &str = "check";
+const ENTRY_POINT_CHECK_FROM_STORED_SESSION: &str = "check_from_stored_session";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ACCOUNT_HASH: &str = "account_hash";
+const ARG_EXPECTED: &str = "expected";
+const ARG_FORWARD: &str = "forward";
+const OTHER_ACCOUNT_HASH: AccountHash = AccountHash::new([42; 32]);
+
+#[repr(u16)]
+enum Error {
+    UnexpectedResult = 0,
+    SessionOfAccountAtTopLevel = 1,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+/// Asserts the result of `caller_is_session_of_account`, optionally after first calling back into
+/// this contract so that the immediate caller is a contract rather than session code.
+#[no_mangle]
+pub extern "C" fn check() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let account_hash: AccountHash = runtime::get_named_arg(ARG_ACCOUNT_HASH);
+    let expected: bool = runtime::get_named_arg(ARG_EXPECTED);
+    let forward: bool = runtime::get_named_arg(ARG_FORWARD);
+
+    if forward {
+        runtime::call_contract::<()>(
+            contract_hash,
+            ENTRY_POINT_CHECK,
+            runtime_args! {
+                ARG_CONTRACT_HASH => contract_hash,
+                ARG_ACCOUNT_HASH => account_hash,
+                ARG_EXPECTED => expected,
+                ARG_FORWARD => false,
+            },
+        );
+    } else if runtime::caller_is_session_of_account(account_hash) != expected {
+        runtime::revert(Error::UnexpectedResult);
+    }
+}
+
+/// Stored session code which expects `check` to see it as session code of `account_hash`.
+#[no_mangle]
+pub extern "C" fn check_from_stored_session() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let account_hash: AccountHash = runtime::get_named_arg(ARG_ACCOUNT_HASH);
+    call_check(contract_hash, account_hash, true, false);
+}
+
+fn call_check(
+    contract_hash: ContractHash,
+    account_hash: AccountHash,
+    expected: bool,
+    forward: bool,
+) {
+    runtime::call_contract::<()>(
+        contract_hash,
+        ENTRY_POINT_CHECK,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_ACCOUNT_HASH => account_hash,
+            ARG_EXPECTED => expected,
+            ARG_FORWARD => forward,
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_POINT_CHECK.to_string(),
+            vec![
+                Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+                Parameter::new(ARG_ACCOUNT_HASH, AccountHash::cl_type()),
+                Parameter::new(ARG_EXPECTED, CLType::Bool),
+                Parameter::new(ARG_FORWARD, CLType::Bool),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        let entry_point = EntryPoint::new(
+            ENTRY_POINT_CHECK_FROM_STORED_SESSION.to_string(),
+            vec![
+                Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+                Parameter::new(ARG_ACCOUNT_HASH, AccountHash::cl_type()),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Session,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+
+    let caller = runtime::get_caller();
+
+    if runtime::caller_is_session_of_account(caller) {
+        runtime::revert(Error::SessionOfAccountAtTopLevel);
+    }
+
+    call_check(contract_hash, caller, true, false);
+    call_check(contract_hash, OTHER_ACCOUNT_HASH, false, false);
+    call_check(contract_hash, caller, false, true);
+
+    runtime::call_contract::<()>(
+        contract_hash,
+        ENTRY_POINT_CHECK_FROM_STORED_SESSION,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_ACCOUNT_HASH => caller,
+        },
+    );
+}
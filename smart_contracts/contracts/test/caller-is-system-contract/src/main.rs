@@ -0,0 +1,113 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::contract_api::{runtime, storage, system};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints},
+    runtime_args, ApiError, CLType, EntryPointAccess, EntryPointType, RuntimeArgs,
+    SystemContractType,
+};
+
+const ENTRY_POINT_CHECK: &str = "check";
+const ENTRY_POINT_CHECK_CALLED_BY_MINT: &str = "check_called_by_mint";
+const ARG_VIA_MINT: &str = "via_mint";
+// Entry point added to the mint by the `modified-system-upgrader` contract.
+const MINT_ENTRY_POINT_FORWARD_CALL: &str = "forward_call";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+
+#[repr(u16)]
+enum Error {
+    CalledByMint = 0,
+    CalledByProofOfStake = 1,
+    CalledByStandardPayment = 2,
+    CalledByAuction = 3,
+    NotCalledByMint = 4,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+fn assert_not_called_by_system_contract() {
+    if system::caller_is_system_contract(SystemContractType::Mint) {
+        runtime::revert(Error::CalledByMint);
+    }
+    if system::caller_is_system_contract(SystemContractType::ProofOfStake) {
+        runtime::revert(Error::CalledByProofOfStake);
+    }
+    if system::caller_is_system_contract(SystemContractType::StandardPayment) {
+        runtime::revert(Error::CalledByStandardPayment);
+    }
+    if system::caller_is_system_contract(SystemContractType::Auction) {
+        runtime::revert(Error::CalledByAuction);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn check() {
+    assert_not_called_by_system_contract();
+}
+
+#[no_mangle]
+pub extern "C" fn check_called_by_mint() {
+    if !system::caller_is_system_contract(SystemContractType::Mint) {
+        runtime::revert(Error::NotCalledByMint);
+    }
+    if system::caller_is_system_contract(SystemContractType::ProofOfStake) {
+        runtime::revert(Error::CalledByProofOfStake);
+    }
+    if system::caller_is_system_contract(SystemContractType::StandardPayment) {
+        runtime::revert(Error::CalledByStandardPayment);
+    }
+    if system::caller_is_system_contract(SystemContractType::Auction) {
+        runtime::revert(Error::CalledByAuction);
+    }
+}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    for name in &[ENTRY_POINT_CHECK, ENTRY_POINT_CHECK_CALLED_BY_MINT] {
+        let entry_point = EntryPoint::new(
+            name.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+    }
+    entry_points
+}
+
+/// Installs a contract and calls it either directly or, if `via_mint` is set, through the mint's
+/// `forward_call` entry point.  The latter requires the mint to have been upgraded by the
+/// `modified-system-upgrader` contract.
+#[no_mangle]
+pub extern "C" fn call() {
+    assert_not_called_by_system_contract();
+
+    let via_mint: bool = runtime::get_named_arg(ARG_VIA_MINT);
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points(), None, None, None);
+
+    if via_mint {
+        runtime::call_contract::<()>(
+            system::get_mint(),
+            MINT_ENTRY_POINT_FORWARD_CALL,
+            runtime_args! {
+                ARG_CONTRACT_HASH => contract_hash,
+                ARG_ENTRY_POINT => ENTRY_POINT_CHECK_CALLED_BY_MINT.to_string(),
+            },
+        );
+    } else {
+        runtime::call_contract::<()>(contract_hash, ENTRY_POINT_CHECK, RuntimeArgs::new());
+    }
+}
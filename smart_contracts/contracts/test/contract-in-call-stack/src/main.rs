@@ -0,0 +1,87 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, Parameter},
+    runtime_args, ApiError, CLType, CLTyped, ContractHash, EntryPointAccess, EntryPointType,
+    RuntimeArgs,
+};
+
+const ENTRY_POINT_GUARDED: &str = "guarded";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_REENTER: &str = "reenter";
+
+#[repr(u16)]
+enum Error {
+    InCallStackOnFirstEntry = 0,
+    NotInCallStackOnReentry = 1,
+    InCallStackOfSession = 2,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn guarded() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let reenter: bool = runtime::get_named_arg(ARG_REENTER);
+
+    let in_call_stack = runtime::contract_in_call_stack(contract_hash);
+
+    if reenter {
+        if in_call_stack {
+            runtime::revert(Error::InCallStackOnFirstEntry);
+        }
+        runtime::call_contract::<()>(
+            contract_hash,
+            ENTRY_POINT_GUARDED,
+            runtime_args! {
+                ARG_CONTRACT_HASH => contract_hash,
+                ARG_REENTER => false,
+            },
+        );
+    } else if !in_call_stack {
+        runtime::revert(Error::NotInCallStackOnReentry);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_POINT_GUARDED.to_string(),
+            vec![
+                Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+                Parameter::new(ARG_REENTER, CLType::Bool),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+
+    if runtime::contract_in_call_stack(contract_hash) {
+        runtime::revert(Error::InCallStackOfSession);
+    }
+
+    runtime::call_contract::<()>(
+        contract_hash,
+        ENTRY_POINT_GUARDED,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_REENTER => true,
+        },
+    );
+}
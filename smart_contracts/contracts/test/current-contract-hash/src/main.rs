@@ -0,0 +1,25 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{ApiError, ContractHash, ContractPackageHash};
+
+const ARG_CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+const ARG_EXPECTED_CONTRACT_HASH: &str = "expected_contract_hash";
+
+#[repr(u16)]
+enum Error {
+    UnexpectedContractHash = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_package_hash: ContractPackageHash =
+        runtime::get_named_arg(ARG_CONTRACT_PACKAGE_HASH);
+    let expected_contract_hash: Option<ContractHash> =
+        runtime::get_named_arg(ARG_EXPECTED_CONTRACT_HASH);
+
+    if storage::current_contract_hash(contract_package_hash) != expected_contract_hash {
+        runtime::revert(ApiError::User(Error::UnexpectedContractHash as u16));
+    }
+}
@@ -0,0 +1,82 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints},
+    ApiError, CLType, ContractPackageHash, EntryPointAccess, EntryPointType, Key,
+};
+
+const ENTRY_POINT_DO_NOTHING: &str = "do_nothing";
+const ARG_CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+
+#[repr(u16)]
+enum Error {
+    UnexpectedVersionCount = 0,
+    UnexpectedVersion = 1,
+    UnexpectedContractHash = 2,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn do_nothing() {}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    let entry_point = EntryPoint::new(
+        ENTRY_POINT_DO_NOTHING.to_string(),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    // When given the hash of an existing package, only query its versions.
+    let maybe_contract_package_hash: Option<ContractPackageHash> =
+        runtime::get_named_arg(ARG_CONTRACT_PACKAGE_HASH);
+    if let Some(contract_package_hash) = maybe_contract_package_hash {
+        storage::get_contract_package_versions(contract_package_hash);
+        return;
+    }
+
+    let (contract_package_hash, _access_uref) = storage::create_contract_package_at_hash();
+
+    let (first_contract_hash, _first_version) =
+        storage::add_contract_version(contract_package_hash, entry_points(), BTreeMap::new());
+    let (second_contract_hash, second_version) =
+        storage::add_contract_version(contract_package_hash, entry_points(), BTreeMap::new());
+
+    storage::disable_contract_version(contract_package_hash, first_contract_hash)
+        .unwrap_or_revert();
+
+    let versions = storage::get_contract_package_versions(contract_package_hash);
+    if versions.len() != 1 {
+        runtime::revert(Error::UnexpectedVersionCount);
+    }
+    let (version_key, contract_hash) = versions.into_iter().next().unwrap_or_revert();
+    if version_key.contract_version() != second_version {
+        runtime::revert(Error::UnexpectedVersion);
+    }
+    if contract_hash != second_contract_hash {
+        runtime::revert(Error::UnexpectedContractHash);
+    }
+
+    runtime::put_key(ARG_CONTRACT_PACKAGE_HASH, Key::Hash(contract_package_hash));
+}
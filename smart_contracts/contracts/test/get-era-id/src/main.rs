@@ -0,0 +1,12 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::contract_api::{runtime, storage};
+
+const ERA_ID_KEY: &str = "era_id";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let era_id = runtime::get_era_id();
+    runtime::put_key(ERA_ID_KEY, storage::new_uref(era_id).into());
+}
@@ -0,0 +1,23 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::contract_api::{runtime, storage};
+
+const ARG_THRESHOLD: &str = "threshold";
+const ITERATIONS_KEY: &str = "iterations";
+const REMAINING_GAS_KEY: &str = "remaining_gas";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let threshold: u64 = runtime::get_named_arg(ARG_THRESHOLD);
+
+    let mut iterations: u64 = 0;
+    let mut remaining_gas = runtime::get_remaining_gas();
+    while remaining_gas >= threshold {
+        iterations += 1;
+        remaining_gas = runtime::get_remaining_gas();
+    }
+
+    runtime::put_key(ITERATIONS_KEY, storage::new_uref(iterations).into());
+    runtime::put_key(REMAINING_GAS_KEY, storage::new_uref(remaining_gas).into());
+}
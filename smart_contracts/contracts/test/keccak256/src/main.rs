@@ -0,0 +1,20 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{Key, KECCAK256_DIGEST_LENGTH};
+
+const ARG_INPUT: &str = "input";
+const DIGEST_KEY_NAME: &str = "digest";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let input: Vec<u8> = runtime::get_named_arg(ARG_INPUT);
+    let digest: [u8; KECCAK256_DIGEST_LENGTH] = runtime::keccak256(input);
+    let digest_uref = storage::new_uref(digest);
+    runtime::put_key(DIGEST_KEY_NAME, Key::from(digest_uref));
+}
@@ -4,7 +4,7 @@
 #[macro_use]
 extern crate alloc;
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String};
 
 use alloc::collections::BTreeMap;
 use casper_contract::{
@@ -17,14 +17,17 @@ use casper_types::{
         ARG_ACCOUNT, ARG_AMOUNT, ARG_PURSE, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
         METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE,
     },
-    CLType, CLValue, ContractHash, ContractVersion, EntryPoint, EntryPointAccess, EntryPointType,
-    EntryPoints, Parameter, URef,
+    CLType, CLTyped, CLValue, ContractHash, ContractVersion, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Parameter, RuntimeArgs, URef,
 };
 
 pub const MODIFIED_MINT_EXT_FUNCTION_NAME: &str = "modified_mint_ext";
 pub const POS_EXT_FUNCTION_NAME: &str = "pos_ext";
 const VERSION_ENTRY_POINT: &str = "version";
 const UPGRADED_VERSION: &str = "1.1.0";
+const FORWARD_CALL_ENTRY_POINT: &str = "forward_call";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
 
 #[no_mangle]
 pub extern "C" fn mint() {
@@ -51,6 +54,14 @@ pub extern "C" fn version() {
     runtime::ret(CLValue::from_t(UPGRADED_VERSION).unwrap_or_revert());
 }
 
+/// Calls the given entry point of the given contract, so that the mint is the callee's caller.
+#[no_mangle]
+pub extern "C" fn forward_call() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+    runtime::call_contract::<()>(contract_hash, &entry_point, RuntimeArgs::new());
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
     standard_payment::delegate();
@@ -78,6 +89,17 @@ fn upgrade_mint() -> (ContractHash, ContractVersion) {
         EntryPointType::Contract,
     );
     entry_points.add_entry_point(entry_point);
+    let entry_point = EntryPoint::new(
+        FORWARD_CALL_ENTRY_POINT,
+        vec![
+            Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+            Parameter::new(ARG_ENTRY_POINT, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
 
     let named_keys = NamedKeys::new();
     storage::add_contract_version(mint_package_hash, entry_points, named_keys)
@@ -0,0 +1,85 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, NamedKeysMigration},
+    ApiError, CLType, CLValue, ContractHash, Key,
+};
+
+const ENTRY_POINT_NAME: &str = "noop";
+const ARG_NAMED_KEYS_MIGRATION: &str = "named_keys_migration";
+const ARG_ALLOWED_NAMES: &str = "allowed_names";
+const NAMED_KEYS_MIGRATION_APPEND_ALL: &str = "append_all";
+const NAMED_KEYS_MIGRATION_DROP_ALL: &str = "drop_all";
+const NAMED_KEYS_MIGRATION_ALLOW_LIST: &str = "allow_list";
+const PACKAGE_HASH_KEY_NAME: &str = "named_keys_migration_package_hash";
+const PREVIOUS_NAMED_KEY_NAMES: [&str; 3] = ["a", "b", "c"];
+const NEW_NAMED_KEY_NAME: &str = "d";
+
+#[no_mangle]
+pub extern "C" fn noop() {}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    let entry_point = EntryPoint::new(
+        ENTRY_POINT_NAME.to_string(),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+    entry_points
+}
+
+fn named_keys_migration() -> NamedKeysMigration {
+    let named_keys_migration: String = runtime::get_named_arg(ARG_NAMED_KEYS_MIGRATION);
+    match named_keys_migration.as_str() {
+        NAMED_KEYS_MIGRATION_APPEND_ALL => NamedKeysMigration::AppendAll,
+        NAMED_KEYS_MIGRATION_DROP_ALL => NamedKeysMigration::DropAll,
+        NAMED_KEYS_MIGRATION_ALLOW_LIST => {
+            let allowed_names: Vec<String> = runtime::get_named_arg(ARG_ALLOWED_NAMES);
+            NamedKeysMigration::AllowList(allowed_names.into_iter().collect())
+        }
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn upgrade() {
+    let named_keys_migration = named_keys_migration();
+
+    let (contract_package_hash, _access_uref) = storage::create_contract_package_at_hash();
+
+    let previous_named_keys = PREVIOUS_NAMED_KEY_NAMES
+        .iter()
+        .map(|name| (name.to_string(), storage::new_uref(*name).into()))
+        .collect();
+    storage::add_contract_version(contract_package_hash, entry_points(), previous_named_keys);
+
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(
+        NEW_NAMED_KEY_NAME.to_string(),
+        storage::new_uref(NEW_NAMED_KEY_NAME).into(),
+    );
+    storage::add_contract_version_with_named_keys_migration(
+        contract_package_hash,
+        entry_points(),
+        named_keys,
+        named_keys_migration,
+    );
+
+    runtime::put_key(PACKAGE_HASH_KEY_NAME, Key::Hash(contract_package_hash));
+
+    let upgrades: BTreeMap<ContractHash, ContractHash> = BTreeMap::new();
+    runtime::ret(CLValue::from_t(upgrades).unwrap());
+}
@@ -13,6 +13,7 @@ enum Error {
     NotFound,
     Invalid,
     IncorrectAccessRights,
+    HostFunctionMismatch,
 }
 
 pub const ARG_PURSE: &str = "purse";
@@ -53,7 +54,7 @@ pub extern "C" fn call() {
     {
         // get_refund_purse should return None before setting it
         let refund_result = get_refund_purse(pos);
-        if refund_result.is_some() {
+        if refund_result.is_some() || system::get_refund_purse().is_some() {
             runtime::revert(ApiError::User(Error::ShouldNotExist as u16));
         }
 
@@ -80,6 +81,12 @@ pub extern "C" fn call() {
             Some(_) => runtime::revert(ApiError::User(Error::Invalid as u16)),
         }
 
+        // the host function should agree with the Proof of Stake contract
+        match system::get_refund_purse() {
+            Some(uref) if uref.addr() == refund_purse.addr() => (),
+            _ => runtime::revert(ApiError::User(Error::HostFunctionMismatch as u16)),
+        }
+
         let payment_amount: U512 = runtime::get_named_arg(ARG_PAYMENT_AMOUNT);
         submit_payment(pos, payment_amount);
     }
@@ -0,0 +1,79 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, Parameter},
+    runtime_args, CLType, CLTyped, ContractHash, EntryPointAccess, EntryPointType, RuntimeArgs,
+};
+
+const ENTRY_POINT_RECURSE: &str = "recurse";
+const ARG_CONTRACT_HASHES: &str = "contract_hashes";
+const ARG_DEPTH: &str = "depth";
+const ARG_DISTINCT_CONTRACTS: &str = "distinct_contracts";
+
+/// Calls the first contract in `contract_hashes`, which in turn calls the rest of them in order.
+fn call_recurse(contract_hashes: &[ContractHash]) {
+    if let Some((contract_hash, rest)) = contract_hashes.split_first() {
+        runtime::call_contract::<()>(
+            *contract_hash,
+            ENTRY_POINT_RECURSE,
+            runtime_args! {
+                ARG_CONTRACT_HASHES => rest.to_vec(),
+            },
+        );
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn recurse() {
+    let contract_hashes: Vec<ContractHash> = runtime::get_named_arg(ARG_CONTRACT_HASHES);
+    call_recurse(&contract_hashes);
+}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    let entry_point = EntryPoint::new(
+        ENTRY_POINT_RECURSE.to_string(),
+        vec![Parameter::new(
+            ARG_CONTRACT_HASHES,
+            Vec::<ContractHash>::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+    entry_points
+}
+
+/// Makes `depth` nested contract calls.
+///
+/// `depth` copies of the contract are always installed, so that the amount of work outside the
+/// calls doesn't depend on `distinct_contracts`.  Each copy has its own wasm hash, so when
+/// `distinct_contracts` is `true` and every call goes to a different copy, no call can reuse a
+/// module parsed earlier in the execution.  Otherwise every call goes to the first copy.
+#[no_mangle]
+pub extern "C" fn call() {
+    let depth: u32 = runtime::get_named_arg(ARG_DEPTH);
+    let distinct_contracts: bool = runtime::get_named_arg(ARG_DISTINCT_CONTRACTS);
+
+    let installed: Vec<ContractHash> = (0..depth)
+        .map(|_| storage::new_contract(entry_points(), None, None, None).0)
+        .collect();
+
+    let contract_hashes = if distinct_contracts {
+        installed
+    } else {
+        installed
+            .first()
+            .map(|contract_hash| vec![*contract_hash; depth as usize])
+            .unwrap_or_default()
+    };
+
+    call_recurse(&contract_hashes);
+}
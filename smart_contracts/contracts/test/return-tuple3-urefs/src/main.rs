@@ -0,0 +1,64 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints},
+    CLTyped, CLValue, EntryPointAccess, EntryPointType, Key, RuntimeArgs, URef,
+};
+
+const ENTRY_POINT_CREATE_UREFS: &str = "create_urefs";
+const FIRST_UREF_NAME: &str = "first";
+const SECOND_UREF_NAME: &str = "second";
+const THIRD_UREF_NAME: &str = "third";
+const INITIAL_VALUE: u64 = 0;
+const WRITTEN_VALUE: u64 = 42;
+
+#[no_mangle]
+pub extern "C" fn create_urefs() {
+    let first = storage::new_uref(INITIAL_VALUE);
+    let second = storage::new_uref(INITIAL_VALUE);
+    let third: Key = storage::new_uref(INITIAL_VALUE).into();
+    let ret = (first, second, third);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_POINT_CREATE_UREFS.to_string(),
+            Vec::new(),
+            <(URef, URef, Key)>::cl_type(),
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+
+    let (first, second, third): (URef, URef, Key) = runtime::call_contract(
+        contract_hash,
+        ENTRY_POINT_CREATE_UREFS,
+        RuntimeArgs::default(),
+    );
+    let third = third.into_uref().unwrap_or_revert();
+
+    // writing requires the access rights returned by the contract to have been granted
+    for uref in &[first, second, third] {
+        storage::write(*uref, WRITTEN_VALUE);
+    }
+
+    runtime::put_key(FIRST_UREF_NAME, first.into());
+    runtime::put_key(SECOND_UREF_NAME, second.into());
+    runtime::put_key(THIRD_UREF_NAME, third.into());
+}
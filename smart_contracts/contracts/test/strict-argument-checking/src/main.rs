@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, NamedKeys},
+    runtime_args, CLType, Parameter, RuntimeArgs,
+};
+
+const ENTRY_POINT_NAME: &str = "take_u64";
+const ARG_VALUE: &str = "value";
+const ARG_MISMATCHED: &str = "mismatched";
+
+#[no_mangle]
+pub extern "C" fn take_u64() {}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mismatched: bool = runtime::get_named_arg(ARG_MISMATCHED);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_POINT_NAME.to_string(),
+            vec![Parameter::new(ARG_VALUE, CLType::U64)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    let (contract_package_hash, _access_uref) = storage::create_contract_package_at_hash();
+    storage::add_contract_version(contract_package_hash, entry_points, NamedKeys::new());
+
+    let args = if mismatched {
+        runtime_args! { ARG_VALUE => "not a u64".to_string() }
+    } else {
+        runtime_args! { ARG_VALUE => 42u64 }
+    };
+    runtime::call_versioned_contract::<()>(contract_package_hash, None, ENTRY_POINT_NAME, args);
+}
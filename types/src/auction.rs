@@ -131,6 +131,7 @@ pub trait Auction: StorageProvider + SystemProvider + RuntimeProvider {
             // are allowed.
             return Err(Error::ValidatorFundsLocked);
         };
+        bid.staked_amount = new_amount;
 
         internal::set_bids(self, bids)?;
 
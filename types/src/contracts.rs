@@ -4,7 +4,7 @@ use crate::{
     alloc::string::ToString,
     bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
     uref::URef,
-    CLType, ContractHash, ContractPackageHash, ContractWasmHash, Key, ProtocolVersion,
+    CLType, CLTyped, ContractHash, ContractPackageHash, ContractWasmHash, Key, ProtocolVersion,
     KEY_HASH_LENGTH,
 };
 use alloc::{
@@ -152,6 +152,12 @@ impl FromBytes for ContractVersionKey {
     }
 }
 
+impl CLTyped for ContractVersionKey {
+    fn cl_type() -> CLType {
+        <(ProtocolVersionMajor, ContractVersion)>::cl_type()
+    }
+}
+
 impl fmt::Display for ContractVersionKey {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}.{}", self.0, self.1)
@@ -457,6 +463,98 @@ impl From<Vec<EntryPoint>> for EntryPoints {
 /// Collection of named keys
 pub type NamedKeys = BTreeMap<String, Key>;
 
+/// Describes which named keys of the current contract version are carried forward to a new
+/// version added to the same contract package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NamedKeysMigration {
+    /// All named keys of the current version are appended to those of the new version.
+    AppendAll,
+    /// None of the named keys of the current version are carried forward.
+    DropAll,
+    /// Only the named keys of the current version with one of the listed names are carried
+    /// forward.
+    AllowList(BTreeSet<String>),
+}
+
+const NAMEDKEYSMIGRATION_APPEND_ALL_TAG: u8 = 0;
+const NAMEDKEYSMIGRATION_DROP_ALL_TAG: u8 = 1;
+const NAMEDKEYSMIGRATION_ALLOW_LIST_TAG: u8 = 2;
+
+impl NamedKeysMigration {
+    /// Constructor for a migration carrying forward only the listed names.
+    pub fn allow_list(names: &[&str]) -> Self {
+        let names = names.iter().map(|name| String::from(*name)).collect();
+        NamedKeysMigration::AllowList(names)
+    }
+
+    /// Merges the named keys of the current contract version, `previous_named_keys`, into
+    /// `named_keys` according to this migration.
+    ///
+    /// On a name collision the previous named key takes precedence.
+    pub fn apply(&self, named_keys: &mut NamedKeys, mut previous_named_keys: NamedKeys) {
+        match self {
+            NamedKeysMigration::AppendAll => named_keys.append(&mut previous_named_keys),
+            NamedKeysMigration::DropAll => {}
+            NamedKeysMigration::AllowList(names) => {
+                for (name, key) in previous_named_keys {
+                    if names.contains(&name) {
+                        named_keys.insert(name, key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Default for NamedKeysMigration {
+    fn default() -> Self {
+        NamedKeysMigration::AppendAll
+    }
+}
+
+impl ToBytes for NamedKeysMigration {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+
+        match self {
+            NamedKeysMigration::AppendAll => {
+                result.push(NAMEDKEYSMIGRATION_APPEND_ALL_TAG);
+            }
+            NamedKeysMigration::DropAll => {
+                result.push(NAMEDKEYSMIGRATION_DROP_ALL_TAG);
+            }
+            NamedKeysMigration::AllowList(names) => {
+                result.push(NAMEDKEYSMIGRATION_ALLOW_LIST_TAG);
+                result.append(&mut names.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        match self {
+            NamedKeysMigration::AppendAll | NamedKeysMigration::DropAll => 1,
+            NamedKeysMigration::AllowList(names) => 1 + names.serialized_length(),
+        }
+    }
+}
+
+impl FromBytes for NamedKeysMigration {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, bytes) = u8::from_bytes(bytes)?;
+
+        match tag {
+            NAMEDKEYSMIGRATION_APPEND_ALL_TAG => Ok((NamedKeysMigration::AppendAll, bytes)),
+            NAMEDKEYSMIGRATION_DROP_ALL_TAG => Ok((NamedKeysMigration::DropAll, bytes)),
+            NAMEDKEYSMIGRATION_ALLOW_LIST_TAG => {
+                let (names, bytes) = BTreeSet::<String>::from_bytes(bytes)?;
+                Ok((NamedKeysMigration::AllowList(names), bytes))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
 /// Methods and type signatures supported by a contract.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Contract {
@@ -1006,6 +1104,46 @@ mod tests {
         assert_eq!(rem.len(), 0);
     }
 
+    #[test]
+    fn named_keys_migration_roundtrip_serialization() {
+        for migration in &[
+            NamedKeysMigration::AppendAll,
+            NamedKeysMigration::DropAll,
+            NamedKeysMigration::allow_list(&["a", "b"]),
+        ] {
+            bytesrepr::test_serialization_roundtrip(migration);
+        }
+    }
+
+    #[test]
+    fn should_apply_named_keys_migration() {
+        let key = |byte| Key::Hash([byte; 32]);
+        let previous_named_keys: NamedKeys = vec![
+            ("a".to_owned(), key(1)),
+            ("b".to_owned(), key(2)),
+            ("c".to_owned(), key(3)),
+        ]
+        .into_iter()
+        .collect();
+        let named_keys: NamedKeys = vec![("d".to_owned(), key(4))].into_iter().collect();
+
+        let mut append_all = named_keys.clone();
+        NamedKeysMigration::AppendAll.apply(&mut append_all, previous_named_keys.clone());
+        assert_eq!(
+            append_all.keys().collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d"]
+        );
+
+        let mut drop_all = named_keys.clone();
+        NamedKeysMigration::DropAll.apply(&mut drop_all, previous_named_keys.clone());
+        assert_eq!(drop_all, named_keys);
+
+        let mut allow_list = named_keys;
+        NamedKeysMigration::allow_list(&["b", "e"]).apply(&mut allow_list, previous_named_keys);
+        assert_eq!(allow_list.keys().collect::<Vec<_>>(), vec!["b", "d"]);
+        assert_eq!(allow_list.get("b"), Some(&key(2)));
+    }
+
     #[test]
     fn should_remove_group() {
         let mut contract_package = make_contract_package();
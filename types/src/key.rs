@@ -21,6 +21,8 @@ const HASH_PREFIX: &str = "hash-";
 
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
+/// The number of bytes in a Keccak-256 hash
+pub const KECCAK256_DIGEST_LENGTH: usize = 32;
 /// The number of bytes in a [`Key::Hash`].
 pub const KEY_HASH_LENGTH: usize = 32;
 
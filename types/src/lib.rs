@@ -66,12 +66,12 @@ pub use contract_wasm::ContractWasm;
 #[doc(inline)]
 pub use key::{
     ContractHash, ContractPackageHash, ContractWasmHash, HashAddr, Key, BLAKE2B_DIGEST_LENGTH,
-    KEY_HASH_LENGTH,
+    KECCAK256_DIGEST_LENGTH, KEY_HASH_LENGTH,
 };
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
 pub use public_key::PublicKey;
-pub use runtime_args::{NamedArg, RuntimeArgs};
+pub use runtime_args::{DuplicateArgumentError, NamedArg, RuntimeArgs};
 pub use semver::{SemVer, SEM_VER_SERIALIZED_LENGTH};
 pub use system_contract_type::SystemContractType;
 pub use transfer_result::{TransferResult, TransferredTo};
@@ -2,6 +2,8 @@
 
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
+use failure::Fail;
+
 use crate::{
     bytesrepr::{self, Error, FromBytes, ToBytes},
     CLTyped, CLValue,
@@ -53,6 +55,19 @@ impl FromBytes for NamedArg {
     }
 }
 
+/// Error returned when [`RuntimeArgs`] holding more than one argument of the same name are put in
+/// canonical order.
+#[derive(Fail, PartialEq, Eq, Clone, Debug)]
+#[fail(display = "duplicated argument name: {}", _0)]
+pub struct DuplicateArgumentError(String);
+
+impl DuplicateArgumentError {
+    /// Returns the duplicated argument name.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
 /// Represents a collection of arguments passed to a smart contract.
 #[derive(PartialEq, Eq, Clone, Debug, Default)]
 pub struct RuntimeArgs(Vec<NamedArg>);
@@ -106,6 +121,36 @@ impl RuntimeArgs {
     pub fn to_values(&self) -> Vec<&CLValue> {
         self.0.iter().map(|NamedArg(_name, value)| value).collect()
     }
+
+    /// Checks if the arguments are in canonical order, i.e. sorted by name with no duplicates.
+    pub fn is_canonical(&self) -> bool {
+        self.0
+            .windows(2)
+            .all(|pair| pair[0].name() < pair[1].name())
+    }
+
+    /// Sorts the arguments by name so that their serialized form doesn't depend on the order of
+    /// insertion.
+    ///
+    /// Returns an error naming the first duplicated argument if any two arguments share a name, as
+    /// such a collection has no canonical order.  The arguments are left sorted in that case.
+    pub fn canonicalize(&mut self) -> Result<(), DuplicateArgumentError> {
+        self.0.sort_by(|lhs, rhs| lhs.name().cmp(rhs.name()));
+        match self
+            .0
+            .windows(2)
+            .find(|pair| pair[0].name() == pair[1].name())
+        {
+            Some(pair) => Err(DuplicateArgumentError(pair[0].name().into())),
+            None => Ok(()),
+        }
+    }
+
+    /// Consumes `self` and returns the arguments in canonical order.
+    pub fn into_canonical(mut self) -> Result<Self, DuplicateArgumentError> {
+        self.canonicalize()?;
+        Ok(self)
+    }
 }
 
 impl From<Vec<NamedArg>> for RuntimeArgs {
@@ -227,6 +272,40 @@ mod tests {
         assert_eq!(tagless, runtime_args_2.to_bytes().unwrap());
     }
 
+    #[test]
+    fn should_canonicalize() {
+        let runtime_args = runtime_args! {
+            "qwer" => Some(1i32),
+            "foo" => 1i32,
+            "bar" => "Foo",
+        };
+        assert!(!runtime_args.is_canonical());
+
+        let canonical = runtime_args.into_canonical().unwrap();
+        assert!(canonical.is_canonical());
+
+        let expected = runtime_args! {
+            "bar" => "Foo",
+            "foo" => 1i32,
+            "qwer" => Some(1i32),
+        };
+        assert_eq!(canonical, expected);
+        assert_eq!(canonical.to_bytes(), expected.to_bytes());
+    }
+
+    #[test]
+    fn duplicated_names_are_not_canonical() {
+        let runtime_args = runtime_args! {
+            "foo" => 1i32,
+            "bar" => 0i32,
+            "foo" => 2i32,
+        };
+        assert!(!runtime_args.is_canonical());
+
+        let error = runtime_args.into_canonical().unwrap_err();
+        assert_eq!(error.name(), "foo");
+    }
+
     #[test]
     fn named_serialization_roundtrip() {
         let args = runtime_args! {